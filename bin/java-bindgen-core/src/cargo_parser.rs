@@ -47,6 +47,48 @@ pub struct Lib {
 pub struct JavaBindgen {
     pub package: Option<String>,
     pub local_mvn_repository: Option<String>,
+    #[serde(alias = "integer-policy")]
+    pub integer_policy: Option<IntegerPolicy>,
+    #[serde(alias = "class-style")]
+    pub class_style: Option<ClassStyle>,
+    pub panic: Option<PanicPolicy>,
+}
+
+// Java mapping of u8, u16, u32, u64, usize, i128, u128
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum IntegerPolicy {
+    // byte, char, int, long (bits reinterpreted)
+    #[default]
+    SameWidth,
+    // short, int, long, BigInteger (next signed Java type)
+    Widen,
+    // java.math.BigInteger
+    BigInteger,
+}
+
+// Java classes generated by #[derive(JavaClass)]
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ClassStyle {
+    // @Data @Builder @AllArgsConstructor class (getters)
+    #[default]
+    Lombok,
+    // Java 16+ record (accessors)
+    Record,
+    // class with constructor, getters, equals and hashCode
+    Pojo,
+}
+
+// Rust panic in a native method
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum PanicPolicy {
+    // throw RustPanicException
+    #[default]
+    Throw,
+    // abort the JVM process
+    Abort,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -95,5 +137,53 @@ pub mod tests {
         assert_eq!("0.1.1", file.package.version);
         assert_eq!("com.test", java_bindgen.package.unwrap_or_default());
         assert_eq!(vec!["cdylib"], file.lib.unwrap().crate_type.unwrap());
+        assert_eq!(None, java_bindgen.integer_policy);
+        assert_eq!(None, java_bindgen.class_style);
+        assert_eq!(None, java_bindgen.panic);
+    }
+
+    #[test]
+    pub fn should_parse_integer_policy() {
+        let toml = r#"
+            [package]
+            name = "mylib"
+            version = "0.1.1"
+
+            [package.metadata.java-bindgen]
+            integer-policy = "big-integer"
+        "#;
+        let file: super::CargoToml = toml::from_str(toml).expect("Valid Cargo.toml");
+        let java_bindgen = file.java_bindgen().unwrap_or_default();
+        assert_eq!(Some(super::IntegerPolicy::BigInteger), java_bindgen.integer_policy);
+    }
+
+    #[test]
+    pub fn should_parse_class_style() {
+        let toml = r#"
+            [package]
+            name = "mylib"
+            version = "0.1.1"
+
+            [package.metadata.java-bindgen]
+            class_style = "record"
+        "#;
+        let file: super::CargoToml = toml::from_str(toml).expect("Valid Cargo.toml");
+        let java_bindgen = file.java_bindgen().unwrap_or_default();
+        assert_eq!(Some(super::ClassStyle::Record), java_bindgen.class_style);
+    }
+
+    #[test]
+    pub fn should_parse_panic_policy() {
+        let toml = r#"
+            [package]
+            name = "mylib"
+            version = "0.1.1"
+
+            [package.metadata.java-bindgen]
+            panic = "abort"
+        "#;
+        let file: super::CargoToml = toml::from_str(toml).expect("Valid Cargo.toml");
+        let java_bindgen = file.java_bindgen().unwrap_or_default();
+        assert_eq!(Some(super::PanicPolicy::Abort), java_bindgen.panic);
     }
 }
@@ -14,18 +14,102 @@ Java FFI method definition
 Example:
 id: hello
 sig: public static native String hello(String input)
+class: ImageOps (optional)
+package: com.acme.image (optional)
+throws: [java.io.IOException, errors::ImageError] (optional)
 */
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
 pub struct JavaFFIMethod {
     pub id: String,
     pub sig: String,
+    // Java class declaring static method (None: lib class)
+    #[serde(default)]
+    pub class: Option<String>,
+    // Java package of the class (None: project package)
+    #[serde(default)]
+    pub package: Option<String>,
+    // Java exception classes or Rust error type paths (#[derive(JavaException)])
+    #[serde(default)]
+    pub throws: Vec<String>,
 }
 
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+/*
+Java class kind
+
+Class: data class (fields)
+Handle: opaque Rust value owned by a Java object (long handle)
+Enum: Java enum (variants)
+Sealed: Java sealed interface (variants as records)
+Exception: Java exception class (variants as nested subclasses)
+*/
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum JavaFFIClassKind {
+    #[default]
+    Class,
+    Handle,
+    Enum,
+    Sealed,
+    Exception,
+}
+
+/*
+Java class field accessor (read by IntoRust)
+
+Getter: getX() / isX() (Lombok class)
+Record: x() (Java record)
+Field: public field x
+*/
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum JavaFFIAccessor {
+    #[default]
+    Getter,
+    Record,
+    Field,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+pub struct JavaFFIVariant {
+    pub id: String,
+    // Record components (Name, Type)
+    #[serde(default)]
+    pub fields: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
 pub struct JavaFFIClass {
+    // Rust type name
     pub id: String,
+    // Java class name (None: same as id)
+    #[serde(default)]
+    pub name: Option<String>,
+    // Java package (None: project package)
+    #[serde(default)]
+    pub package: Option<String>,
     // Type, name
     pub fields: Vec<(String, String)>,
+    #[serde(default)]
+    pub kind: JavaFFIClassKind,
+    // Native methods declared in class body
+    #[serde(default)]
+    pub methods: Vec<JavaFFIMethod>,
+    // Enum variants
+    #[serde(default)]
+    pub variants: Vec<JavaFFIVariant>,
+    // Class accessor style
+    #[serde(default)]
+    pub accessor: JavaFFIAccessor,
+    // Fields with own accessor (Field name, Accessor, Java member name)
+    #[serde(default)]
+    pub field_accessors: Vec<(String, JavaFFIAccessor, String)>,
+    // Exception super class (None: java.lang.RuntimeException)
+    #[serde(default)]
+    pub extends: Option<String>,
+}
+
+impl JavaFFIClass {
+    pub fn java_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.id)
+    }
 }
 
 #[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
@@ -59,6 +143,14 @@ impl FFIStore {
         self.classes.clone()
     }
 
+    // Rust type name -> Java class name
+    pub fn get_java_class_name(&self, id: &str) -> Option<String> {
+        self.classes
+            .iter()
+            .find(|c| c.id == id)
+            .map(|c| c.java_name().to_string())
+    }
+
     pub fn path(&self) -> Option<PathBuf> {
         self.file_path.clone()
     }
@@ -112,7 +204,7 @@ impl Drop for FFIStore {
 
 #[cfg(test)]
 pub mod tests {
-    use super::{FFIStore, JavaFFIMethod};
+    use super::{FFIStore, JavaFFIAccessor, JavaFFIClassKind, JavaFFIMethod};
     use std::{fs, path::Path};
 
     pub fn create_test_store() -> FFIStore {
@@ -138,6 +230,7 @@ pub mod tests {
         store.add_ffi_method(JavaFFIMethod {
             id: "test_id".to_string(),
             sig: "sig".to_string(),
+            ..Default::default()
         });
         let store_path = store.path().expect("Store path").clone();
 
@@ -151,4 +244,19 @@ pub mod tests {
         assert!(json.contains("test_id"));
         assert!(json.contains("sig"));
     }
+
+    #[test]
+    pub fn should_read_class_without_kind() {
+        let store = FFIStore::from_json(
+            r#"{"methods":[],"classes":[{"id":"User","fields":[["name","String"]]}]}"#.to_string(),
+        );
+        let classes = store.get_classes();
+        assert_eq!(1, classes.len());
+        assert_eq!(JavaFFIClassKind::Class, classes[0].kind);
+        assert!(classes[0].methods.is_empty());
+        assert_eq!(JavaFFIAccessor::Getter, classes[0].accessor);
+        assert!(classes[0].field_accessors.is_empty());
+        assert_eq!("User", classes[0].java_name());
+        assert_eq!(None, classes[0].package);
+    }
 }
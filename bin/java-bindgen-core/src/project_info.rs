@@ -1,11 +1,14 @@
 use std::path::PathBuf;
 
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ProjectInfo {
     pub java_package_name: String,
     pub lib_name: String,
     pub lib_version: String,
+    pub integer_policy: crate::cargo_parser::IntegerPolicy,
+    pub class_style: crate::cargo_parser::ClassStyle,
+    pub panic_policy: crate::cargo_parser::PanicPolicy,
 }
 
 impl ProjectInfo {
@@ -46,20 +49,43 @@ impl ProjectInfo {
             .to_case(convert_case::Case::Pascal)
     }
 
-    #[allow(dead_code)]
-    pub fn get_java_method_name(&self, method_name: &str) -> String {
+    // Static method of class (None: lib class)
+    pub fn get_java_method_name(&self, class_name: Option<&str>, method_name: &str) -> String {
+        match class_name.filter(|c| !c.is_empty()) {
+            Some(class_name) => self.get_java_class_method_name(class_name, method_name),
+            None => self.get_java_class_method_name(&self.get_java_class_name(), method_name),
+        }
+    }
+
+    pub fn get_java_class_method_name(&self, class_name: &str, method_name: &str) -> String {
         let packages = self.get_packages_path();
-        let class_name = self.get_java_class_name();
 
         // Example: Java_com_test_MyLib_hello
         // package: com.test
         // class: MyLib
         // fn: hello
-        format!("Java_{}_{}_{}", packages.join("_"), class_name, method_name.replace('_', "_1"))
+        format!(
+            "Java_{}_{}_{}",
+            packages.join("_"),
+            class_name.replace('_', "_1"),
+            method_name.replace('_', "_1")
+        )
     }
 
     pub fn get_packages_path(&self) -> Vec<String> {
-        self.java_package_name
+        self.get_class_packages_path(None)
+    }
+
+    // Java package of class (None: project package)
+    pub fn get_class_package_name(&self, package: Option<&str>) -> String {
+        package
+            .filter(|p| !p.is_empty())
+            .unwrap_or(&self.java_package_name)
+            .to_string()
+    }
+
+    pub fn get_class_packages_path(&self, package: Option<&str>) -> Vec<String> {
+        self.get_class_package_name(package)
             .to_lowercase()
             .split('.')
             .map(|s| s.to_string())
@@ -74,6 +100,9 @@ impl From<&crate::cargo_parser::CargoToml> for ProjectInfo {
             lib_name: value.package.name.clone(),
             lib_version: value.package.version.clone(),
             java_package_name: java_bindgen.package.unwrap_or_default(),
+            integer_policy: java_bindgen.integer_policy.unwrap_or_default(),
+            class_style: java_bindgen.class_style.unwrap_or_default(),
+            panic_policy: java_bindgen.panic.unwrap_or_default(),
         }
     }
 }
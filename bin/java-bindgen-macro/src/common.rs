@@ -7,11 +7,20 @@ use syn::Type;
 use syn::__private::TokenStream2;
 use syn::{spanned::Spanned, ReturnType};
 
-use crate::{types_conversion::rewrite_rust_to_java, util::CompileErrors};
+use java_bindgen_core::{
+    cargo_parser::{ClassStyle, IntegerPolicy},
+    ffi_store::JavaFFIAccessor,
+};
+
+use crate::{
+    types_conversion::{find_primitive_slice, rewrite_rust_to_java},
+    util::{parse_attr_str_to_map, CompileErrors},
+};
 
 // Java arguments list for FFI interface (Java side arguments list)
 pub fn produce_java_args(
     inputs: &syn::punctuated::Punctuated<syn::FnArg, syn::Token![,]>,
+    policy: IntegerPolicy,
     errors: &mut CompileErrors,
 ) -> Vec<String> {
     let mut args = vec![];
@@ -19,7 +28,7 @@ pub fn produce_java_args(
         match ele {
             FnArg::Receiver(_) => {}
             FnArg::Typed(typed) => {
-                if let Some(java_type) = to_java_type(&typed.ty, errors) {
+                if let Some(java_type) = to_java_type(&typed.ty, policy, errors) {
                     let name = typed.pat.to_token_stream().to_string();
                     args.push(format!("{java_type} {name}"))
                 }
@@ -29,14 +38,23 @@ pub fn produce_java_args(
     args
 }
 
-pub fn produce_java_return(return_type: &TokenStream2, errors: &mut CompileErrors) -> String {
-    if let Some(new_type) = rewrite_rust_to_java(return_type, errors) {
+pub fn produce_java_return(return_type: &TokenStream2, policy: IntegerPolicy, errors: &mut CompileErrors) -> String {
+    if let Some(new_type) = rewrite_rust_to_java(return_type, policy, errors) {
         return new_type;
     }
 
     "void".to_string()
 }
 
+// `java_bindgen::interop::JIntegerPolicy` entered by generated native methods (integer-policy)
+pub fn j_integer_policy(policy: IntegerPolicy) -> TokenStream2 {
+    match policy {
+        IntegerPolicy::SameWidth => quote! { java_bindgen::interop::JIntegerPolicy::SameWidth },
+        IntegerPolicy::Widen => quote! { java_bindgen::interop::JIntegerPolicy::Widen },
+        IntegerPolicy::BigInteger => quote! { java_bindgen::interop::JIntegerPolicy::BigInteger },
+    }
+}
+
 pub enum BindgenReturnType {
     JResult(TokenStream2),
     Option(TokenStream2),
@@ -68,8 +86,10 @@ pub fn produce_rust_result_type(r_type: &ReturnType, errors: &mut CompileErrors)
                 // <T>
                 let inner_type = arg.args.to_token_stream();
 
+                // JResult<T, E>: E is thrown
                 if segment_indent_str.contains("JResult") {
-                    return BindgenReturnType::JResult(inner_type);
+                    let ok_type = arg.args.first().map(|ty| ty.to_token_stream()).unwrap_or(inner_type);
+                    return BindgenReturnType::JResult(ok_type);
                 }
 
                 if segment_indent_str.contains("Option") {
@@ -90,6 +110,28 @@ pub fn produce_rust_result_type(r_type: &ReturnType, errors: &mut CompileErrors)
     BindgenReturnType::None(TokenStream2::default())
 }
 
+// E from JResult<T, E> (type name)
+pub fn produce_rust_error_type(r_type: &ReturnType) -> Option<String> {
+    let ReturnType::Type(_, r_type) = r_type else {
+        return None;
+    };
+    let Type::Path(ref path) = **r_type else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if !segment.ident.to_string().contains("JResult") {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(ref arg) = segment.arguments else {
+        return None;
+    };
+    let Some(syn::GenericArgument::Type(Type::Path(error))) = arg.args.iter().nth(1) else {
+        return None;
+    };
+    // path as written (ImageError, errors::ImageError)
+    let segments: Vec<String> = error.path.segments.iter().map(|s| s.ident.to_string()).collect();
+    Some(segments.join("::"))
+}
 
 // Rust fn arguments for inner function call
 pub fn produce_rust_args_names(
@@ -102,8 +144,7 @@ pub fn produce_rust_args_names(
             FnArg::Receiver(r) => {
                 errors.add_spaned(
                     r.span(),
-                    "'self' parameter is not supported. Use functions for defining java bindings."
-                        .into(),
+                    "`self` is only supported inside #[java_bindgen] impl blocks".into(),
                 );
                 args.append_all(quote! { self });
             }
@@ -112,6 +153,8 @@ pub fn produce_rust_args_names(
                 let mut is_mute = quote! {};
                 if type_string.contains("&mut") {
                     is_mute = quote! { &mut }
+                } else if find_primitive_slice(&type_string).is_some() {
+                    is_mute = quote! { & }
                 }
 
                 let name = if let syn::Pat::Ident(ref patpath) = *typed.pat {
@@ -133,7 +176,7 @@ pub fn produce_rust_args_names(
 
 
 // determine java type based on Rust type
-pub fn to_java_type(rust_type: &Type, errors: &mut CompileErrors) -> Option<String> {
+pub fn to_java_type(rust_type: &Type, policy: IntegerPolicy, errors: &mut CompileErrors) -> Option<String> {
     let mut add_error = |msg: &str| {
         errors.add_spaned(rust_type.span(), format!("'{msg}' is not supported"));
         None
@@ -149,12 +192,15 @@ pub fn to_java_type(rust_type: &Type, errors: &mut CompileErrors) -> Option<Stri
         Type::Macro(_) => None,
         Type::Never(_) => add_error("never type"),
         Type::Paren(_) => add_error("parenthesized type"),
-        Type::Path(t) => rewrite_rust_to_java(&t.to_token_stream(), errors),
+        Type::Path(t) => rewrite_rust_to_java(&t.to_token_stream(), policy, errors),
         Type::Ptr(_) => add_error("*"),
         Type::Reference(_) => {
             if rust_type_str.contains("JNIEnv") {
                 return None;
             }
+            if let Some((_, java_type, _)) = find_primitive_slice(&rust_type_str) {
+                return Some(java_type.to_string());
+            }
             add_error("&")
         }
         Type::Slice(_) => add_error("slice"),
@@ -166,7 +212,158 @@ pub fn to_java_type(rust_type: &Type, errors: &mut CompileErrors) -> Option<Stri
 }
 
 
-pub fn get_struct_fileds(fields: &syn::Fields, errors: &mut CompileErrors) -> Vec<(syn::Ident, Type)> {
+// #[java(key = "value", ...)] attributes
+pub fn parse_java_attrs(
+    attrs: &[syn::Attribute],
+    allowed: &[&str],
+    errors: &mut CompileErrors,
+) -> std::collections::HashMap<String, String> {
+    let mut result = std::collections::HashMap::new();
+    for attr in attrs.iter().filter(|a| a.path().is_ident("java")) {
+        let syn::Meta::List(ref list) = attr.meta else {
+            errors.add_spaned(attr.span(), "Use: #[java(key = \"value\")]".to_string());
+            continue;
+        };
+        for (key, value) in parse_attr_str_to_map(&list.tokens.to_string()) {
+            if key.is_empty() {
+                continue;
+            }
+            if !allowed.contains(&key.as_str()) {
+                errors.add_spaned(
+                    list.span(),
+                    format!("Unknown attribute: '{key}'. Allowed: {}", allowed.join(", ")),
+                );
+                continue;
+            }
+            result.insert(key, value);
+        }
+    }
+    result
+}
+
+fn parse_accessor(value: &str, span: syn::__private::Span, errors: &mut CompileErrors) -> Option<JavaFFIAccessor> {
+    match value {
+        "getter" => Some(JavaFFIAccessor::Getter),
+        "record" => Some(JavaFFIAccessor::Record),
+        "field" => Some(JavaFFIAccessor::Field),
+        _ => {
+            errors.add_spaned(
+                span,
+                format!("Unknown accessor: '{value}'. Use: \"getter\", \"record\" or \"field\""),
+            );
+            None
+        }
+    }
+}
+
+// class_style = "record" -> x(), "lombok" / "pojo" -> getX()
+fn default_accessor(class_style: ClassStyle) -> JavaFFIAccessor {
+    match class_style {
+        ClassStyle::Record => JavaFFIAccessor::Record,
+        ClassStyle::Lombok | ClassStyle::Pojo => JavaFFIAccessor::Getter,
+    }
+}
+
+pub fn is_bool_type(ty: &syn::Type) -> bool {
+    let ty_string = ty.to_token_stream().to_string().replace(" ", "");
+    if ty_string == "bool" || ty_string == "Option<bool>" {
+        return true
+    }
+    false
+}
+
+// #[java(rename_all = "camelCase")] (Rust snake_case field names)
+fn rename_field(name: &str, rule: &str) -> Option<String> {
+    let words: Vec<&str> = name.split('_').filter(|w| !w.is_empty()).collect();
+    let capitalize = |w: &str| -> String {
+        let mut chars = w.chars();
+        chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default()
+    };
+    let renamed = match rule {
+        "camelCase" => words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| if i == 0 { w.to_string() } else { capitalize(w) })
+            .collect(),
+        "PascalCase" => words.iter().map(|w| capitalize(w)).collect(),
+        "snake_case" => name.to_string(),
+        "SCREAMING_SNAKE_CASE" => name.to_uppercase(),
+        "lowercase" => words.concat(),
+        "UPPERCASE" => words.concat().to_uppercase(),
+        _ => return None,
+    };
+    Some(renamed)
+}
+
+pub struct StructField {
+    pub ident: syn::Ident,
+    pub ty: Type,
+    // Java field name: #[java(rename = "fullName")] / #[java(rename_all = "camelCase")]
+    pub java_field_name: String,
+    // #[java(accessor = "field")]
+    pub accessor: Option<JavaFFIAccessor>,
+    // #[java(name = "fullName")]
+    pub java_name: Option<String>,
+    // #[java(skip)]: not in Java class, Default::default() in Rust
+    pub skip: bool,
+    // #[java(default)]: Java null -> Default::default()
+    pub default: bool,
+}
+
+impl StructField {
+    pub fn accessor(&self, class_accessor: JavaFFIAccessor) -> JavaFFIAccessor {
+        self.accessor.unwrap_or(class_accessor)
+    }
+
+    // Java member read by IntoRust: getX() / isX(), x() or field x
+    pub fn java_member(&self, class_accessor: JavaFFIAccessor) -> String {
+        if let Some(ref name) = self.java_name {
+            return name.clone();
+        }
+        let name = &self.java_field_name;
+        match self.accessor(class_accessor) {
+            JavaFFIAccessor::Getter => {
+                let prefix = if is_bool_type(&self.ty) { "is" } else { "get" };
+                // Lombok keeps boolean `isX` fields as they are: isDone -> isDone()
+                if prefix == "is" && name.strip_prefix("is").and_then(|n| n.chars().next()).is_some_and(char::is_uppercase) {
+                    return name.clone();
+                }
+                match name.chars().next() {
+                    Some(c) => format!("{prefix}{}{}", c.to_uppercase(), &name[c.len_utf8()..]),
+                    None => prefix.to_string(),
+                }
+            }
+            JavaFFIAccessor::Record | JavaFFIAccessor::Field => name.clone(),
+        }
+    }
+
+    // Java field name (written by IntoJava for public fields)
+    pub fn java_field(&self, class_accessor: JavaFFIAccessor) -> String {
+        match self.accessor(class_accessor) {
+            JavaFFIAccessor::Field => self.java_member(class_accessor),
+            _ => self.java_field_name.clone(),
+        }
+    }
+}
+
+pub struct StructData {
+    // #[java(accessor = "record")]
+    pub accessor: JavaFFIAccessor,
+    pub fields: Vec<StructField>,
+}
+
+impl StructData {
+    // Fields present in Java class (without #[java(skip)])
+    pub fn java_fields(&self) -> impl Iterator<Item = &StructField> {
+        self.fields.iter().filter(|f| !f.skip)
+    }
+}
+
+pub fn get_struct_fileds(
+    fields: &syn::Fields,
+    rename_all: Option<&str>,
+    errors: &mut CompileErrors,
+) -> Vec<StructField> {
     let mut result = vec![];
     for field in fields.iter() {
         let Some(ref name) = field.ident else {
@@ -176,27 +373,211 @@ pub fn get_struct_fileds(fields: &syn::Fields, errors: &mut CompileErrors) -> Ve
             );
             continue;
         };
-        result.push((name.clone(), field.ty.clone()));
+        let attr = parse_java_attrs(
+            &field.attrs,
+            &["accessor", "name", "rename", "skip", "default"],
+            errors,
+        );
+        let accessor = attr
+            .get("accessor")
+            .and_then(|value| parse_accessor(value, field.span(), errors));
+        let java_name = attr.get("name").filter(|name| !name.is_empty()).cloned();
+
+        let rust_name = name.to_string();
+        let java_field_name = match (attr.get("rename").filter(|r| !r.is_empty()), rename_all) {
+            (Some(rename), _) => rename.clone(),
+            (None, Some(rule)) => rename_field(&rust_name, rule).unwrap_or(rust_name),
+            (None, None) => rust_name,
+        };
+
+        result.push(StructField {
+            ident: name.clone(),
+            ty: field.ty.clone(),
+            java_field_name,
+            accessor,
+            java_name,
+            skip: attr.contains_key("skip"),
+            default: attr.contains_key("default"),
+        });
+    }
+    result
+}
+
+pub struct EnumVariant {
+    pub ident: syn::Ident,
+    // (Rust member, Java name, Type)
+    pub fields: Vec<(syn::Member, String, Type)>,
+}
+
+pub fn get_enum_variants(data: &syn::DataEnum) -> Vec<EnumVariant> {
+    let mut result = vec![];
+    for variant in data.variants.iter() {
+        let mut fields = vec![];
+        for (i, field) in variant.fields.iter().enumerate() {
+            // Tuple variant fields: _0, _1, ...
+            let (member, java_name) = match field.ident {
+                Some(ref name) => (syn::Member::Named(name.clone()), name.to_string()),
+                None => (syn::Member::Unnamed(syn::Index::from(i)), format!("_{i}")),
+            };
+            fields.push((member, java_name, field.ty.clone()));
+        }
+        result.push(EnumVariant {
+            ident: variant.ident.clone(),
+            fields,
+        });
     }
     result
 }
 
+pub enum ClassData {
+    Struct(StructData),
+    // Java enum (fieldless variants)
+    Enum(Vec<syn::Ident>),
+    // Java sealed interface (variant -> record)
+    Sealed(Vec<EnumVariant>),
+}
+
+// Rust struct / enum -> Java class definition (saved in FFIStore)
+pub fn register_java_class(
+    project_info: &java_bindgen_core::project_info::ProjectInfo,
+    input: &syn::DeriveInput,
+    errors: &mut CompileErrors,
+) -> Option<ClassData> {
+    use java_bindgen_core::ffi_store::{FFIStore, JavaFFIClass, JavaFFIClassKind, JavaFFIVariant};
+
+    let (mut class, data) = match input.data {
+        syn::Data::Struct(ref struct_info) => {
+            let attr = parse_java_attrs(&input.attrs, &["accessor", "rename_all", "package", "name"], errors);
+            let accessor = attr
+                .get("accessor")
+                .and_then(|value| parse_accessor(value, input.ident.span(), errors))
+                .unwrap_or_else(|| default_accessor(project_info.class_style));
+            let rename_all = attr.get("rename_all").map(|rule| rule.as_str());
+            if let Some(rule) = rename_all.filter(|rule| rename_field("", rule).is_none()) {
+                errors.add_spaned(
+                    input.ident.span(),
+                    format!(
+                        "Unknown rename_all: '{rule}'. Use: \"camelCase\", \"PascalCase\", \"snake_case\", \"SCREAMING_SNAKE_CASE\", \"lowercase\" or \"UPPERCASE\""
+                    ),
+                );
+            }
+            let fields = get_struct_fileds(&struct_info.fields, rename_all, errors);
+            let java_fields = produce_java_class_ffi_types(&fields, project_info.integer_policy, errors)?;
+
+            let mut field_accessors = vec![];
+            let custom_accessors = fields
+                .iter()
+                .filter(|f| !f.skip && (f.accessor.is_some() || f.java_name.is_some()));
+            for field in custom_accessors {
+                let field_accessor = field.accessor(accessor);
+                if accessor == JavaFFIAccessor::Record && field_accessor == JavaFFIAccessor::Field {
+                    errors.add_spaned(
+                        field.ident.span(),
+                        "Java record fields are private. Use: #[java(accessor = \"record\")]".to_string(),
+                    );
+                }
+                field_accessors.push((field.java_field_name.clone(), field_accessor, field.java_member(accessor)));
+            }
+
+            let class = JavaFFIClass {
+                id: input.ident.to_string(),
+                fields: java_fields,
+                accessor,
+                field_accessors,
+                ..Default::default()
+            };
+            (class, ClassData::Struct(StructData { accessor, fields }))
+        }
+        syn::Data::Enum(ref enum_info) => {
+            parse_java_attrs(&input.attrs, &["package", "name"], errors);
+            let variants = get_enum_variants(enum_info);
+            let mut java_variants = vec![];
+            for variant in variants.iter() {
+                let mut fields = vec![];
+                for (_, java_name, ty) in variant.fields.iter() {
+                    if let Some(java_ty) = rewrite_rust_to_java(&ty.to_token_stream(), project_info.integer_policy, errors) {
+                        fields.push((java_name.clone(), java_ty));
+                    }
+                }
+                java_variants.push(JavaFFIVariant {
+                    id: variant.ident.to_string(),
+                    fields,
+                });
+            }
+
+            if variants.iter().all(|v| v.fields.is_empty()) {
+                let class = JavaFFIClass {
+                    id: input.ident.to_string(),
+                    kind: JavaFFIClassKind::Enum,
+                    variants: java_variants,
+                    ..Default::default()
+                };
+                (class, ClassData::Enum(variants.into_iter().map(|v| v.ident).collect()))
+            } else {
+                let class = JavaFFIClass {
+                    id: input.ident.to_string(),
+                    kind: JavaFFIClassKind::Sealed,
+                    variants: java_variants,
+                    ..Default::default()
+                };
+                (class, ClassData::Sealed(variants))
+            }
+        }
+        syn::Data::Union(_) => {
+            errors.add("Only struct or enum is allowed.".to_string());
+            return None;
+        }
+    };
+
+    class.name = class_attr(input, "name");
+    class.package = class_attr(input, "package");
+
+    let project_dir = std::path::Path::new(".");
+    let ffi_path = java_bindgen_core::consts::ffi_definitions_path(project_dir);
+    if let Some(mut store) = FFIStore::read_from_file(&ffi_path) {
+        store.add_ffi_class(class);
+        store.save();
+    }
+    Some(data)
+}
+
 pub fn produce_java_class_ffi_types(
-    rust_types: &Vec<(syn::Ident, Type)>,
+    rust_types: &[StructField],
+    policy: IntegerPolicy,
     errors: &mut CompileErrors,
 ) -> Option<Vec<(String, String)>> {
     let mut java_types = vec![];
-    for (name, ty) in rust_types {
-        let Some(java_ty) = crate::types_conversion::rewrite_rust_to_java(&ty.to_token_stream(), errors) else {
+    for field in rust_types.iter().filter(|f| !f.skip) {
+        let Some(java_ty) = crate::types_conversion::rewrite_rust_to_java(&field.ty.to_token_stream(), policy, errors) else {
             continue;
         };
-        java_types.push((name.to_string(), java_ty));
+        java_types.push((field.java_field_name.clone(), java_ty));
     }
 
     Some(java_types)
 }
 
 
+// #[java(package = "com.acme.model", name = "ImageRequest")] (errors reported by register_java_class)
+fn class_attr(input: &syn::DeriveInput, key: &str) -> Option<String> {
+    parse_java_attrs(&input.attrs, &[key], &mut CompileErrors::default())
+        .remove(key)
+        .filter(|value| !value.is_empty())
+}
+
+// Java class name (default: Rust type name)
+pub fn java_class_name(input: &syn::DeriveInput) -> String {
+    class_attr(input, "name").unwrap_or_else(|| input.ident.to_string())
+}
+
+// Project info with Java class package
+pub fn class_project_info(
+    project_info: java_bindgen_core::project_info::ProjectInfo,
+    input: &syn::DeriveInput,
+) -> java_bindgen_core::project_info::ProjectInfo {
+    project_info.set_package_name(&class_attr(input, "package").unwrap_or_default())
+}
+
 pub fn class_path(project_info: &java_bindgen_core::project_info::ProjectInfo, class_name: String) -> TokenStream2 {
     use std::str::FromStr;
 
@@ -205,3 +586,94 @@ pub fn class_path(project_info: &java_bindgen_core::project_info::ProjectInfo, c
     let class_path = class_path.join("/");
     TokenStream2::from_str(&format!("\"{class_path}\"")).unwrap_or(quote! {})
 }
+
+#[cfg(test)]
+mod tests {
+    use super::get_struct_fileds;
+    use crate::util::CompileErrors;
+    use java_bindgen_core::ffi_store::JavaFFIAccessor;
+
+    #[test]
+    fn should_resolve_java_members() {
+        let input: syn::ItemStruct = syn::parse_quote! {
+            struct Label {
+                text: String,
+                visible: bool,
+                #[java(accessor = "field", name = "label_size")]
+                size: i32,
+                #[java(name = "fontName")]
+                font: String,
+            }
+        };
+        let mut errors = CompileErrors::default();
+        let fields = get_struct_fileds(&input.fields, None, &mut errors);
+        let members = |accessor| -> Vec<String> { fields.iter().map(|f| f.java_member(accessor)).collect() };
+
+        assert_eq!(members(JavaFFIAccessor::Getter), ["getText", "isVisible", "label_size", "fontName"]);
+        assert_eq!(members(JavaFFIAccessor::Record), ["text", "visible", "label_size", "fontName"]);
+        assert_eq!(fields[2].java_field(JavaFFIAccessor::Record), "label_size");
+        assert_eq!(fields[3].java_field(JavaFFIAccessor::Field), "fontName");
+        assert_eq!(fields[3].java_field(JavaFFIAccessor::Getter), "font");
+    }
+
+    #[test]
+    fn should_keep_error_type_path() {
+        let sig: syn::Signature = syn::parse_quote! { fn read() -> JResult<Vec<u8>, storage::IOException> };
+        assert_eq!(super::produce_rust_error_type(&sig.output).as_deref(), Some("storage::IOException"));
+        let sig: syn::Signature = syn::parse_quote! { fn read() -> JResult<Vec<u8>> };
+        assert_eq!(super::produce_rust_error_type(&sig.output), None);
+    }
+
+    #[test]
+    fn should_reject_self_outside_impl_blocks() {
+        use quote::ToTokens;
+
+        let sig: syn::Signature = syn::parse_quote! { fn get(&self, value: i32) };
+        let mut errors = CompileErrors::default();
+        super::produce_rust_args_names(&sig.inputs, &mut errors);
+        let errors = errors.to_token_stream().to_string();
+        assert!(errors.contains("`self` is only supported inside #[java_bindgen] impl blocks"));
+    }
+
+    #[test]
+    fn should_resolve_non_ascii_getters() {
+        let input: syn::ItemStruct = syn::parse_quote! {
+            struct Café {
+                été: String,
+                ñandú: bool,
+            }
+        };
+        let mut errors = CompileErrors::default();
+        let fields = get_struct_fileds(&input.fields, None, &mut errors);
+        let members: Vec<String> = fields.iter().map(|f| f.java_member(JavaFFIAccessor::Getter)).collect();
+        assert_eq!(members, ["getÉté", "isÑandú"]);
+    }
+
+    #[test]
+    fn should_rename_fields() {
+        let input: syn::ItemStruct = syn::parse_quote! {
+            struct TransformResult {
+                applied_transforms: Vec<String>,
+                #[java(rename = "imageExt")]
+                ext: String,
+                #[java(skip)]
+                cache: Vec<u8>,
+                #[java(default)]
+                is_done: bool,
+            }
+        };
+        let mut errors = CompileErrors::default();
+        let fields = get_struct_fileds(&input.fields, Some("camelCase"), &mut errors);
+        let names: Vec<&str> = fields.iter().map(|f| f.java_field_name.as_str()).collect();
+        assert_eq!(names, ["appliedTransforms", "imageExt", "cache", "isDone"]);
+        assert_eq!(fields[0].java_member(JavaFFIAccessor::Getter), "getAppliedTransforms");
+        assert_eq!(fields[1].java_member(JavaFFIAccessor::Record), "imageExt");
+        assert!(fields[2].skip && !fields[2].default);
+        assert!(fields[3].default && !fields[3].skip);
+        assert_eq!(fields[3].java_member(JavaFFIAccessor::Getter), "isDone");
+
+        assert_eq!(Some("AppliedTransforms".to_string()), super::rename_field("applied_transforms", "PascalCase"));
+        assert_eq!(Some("APPLIED_TRANSFORMS".to_string()), super::rename_field("applied_transforms", "SCREAMING_SNAKE_CASE"));
+        assert_eq!(None, super::rename_field("applied_transforms", "kebab-case"));
+    }
+}
@@ -1,78 +1,86 @@
-use java_bindgen_core::{
-    consts::ffi_definitions_path,
-    ffi_store::{FFIStore, JavaFFIClass},
-};
+use java_bindgen_core::{ffi_store::JavaFFIAccessor, project_info::ProjectInfo};
 use proc_macro::TokenStream;
-use quote::{quote, ToTokens, TokenStreamExt};
-use syn::{Data, DeriveInput};
+use quote::{quote, TokenStreamExt};
+use syn::DeriveInput;
 use syn::__private::TokenStream2;
 
-use crate::util::{ts2, CompileErrors};
+use crate::{
+    common::{self, ClassData, EnumVariant, StructData},
+    util::{self, CompileErrors},
+};
 
 pub fn main(item: TokenStream) -> TokenStream {
     if let Ok(input) = syn::parse::<DeriveInput>(item.clone()) {
         let project_dir = std::path::Path::new(".");
         let mut errors = CompileErrors::default();
-        let name = &input.ident;
 
-        // Struct Guard
-        let Data::Struct(ref struct_info) = input.data else {
-            errors.add("Only struct is allowed.".to_string());
-            return errors.into();
+        // Parse Cargo.toml file
+        let cargo_toml = match util::parse_project_toml(project_dir) {
+            Ok(toml) => toml,
+            Err(err) => {
+                return util::error(input.ident.span(), err.to_string()).into();
+            }
         };
 
         // Create project info
-        let fields = crate::common::get_struct_fileds(&struct_info.fields, &mut errors);
-        let Some(java_fields) = crate::common::produce_java_class_ffi_types(&fields, &mut errors)
-        else {
+        let project_info = crate::common::class_project_info(ProjectInfo::from(&cargo_toml), &input);
+        let Some(data) = common::register_java_class(&project_info, &input, &mut errors) else {
             return errors.into();
         };
 
-        if let Some(mut store) = FFIStore::read_from_file(&ffi_definitions_path(project_dir)) {
-            store.add_ffi_class(JavaFFIClass {
-                id: name.to_string(),
-                fields: java_fields,
-            });
-            store.save();
-        }
-
-        return impl_into_rust(&input, &fields, &errors).into()
+        return impl_into_rust(&project_info, &input, &data, &errors).into()
     }
 
     item
 }
 
-fn is_bool_type(ty: &syn::Type) -> bool {
-    let ty_string = ty.to_token_stream().to_string().replace(" ", "");
-    if ty_string == "bool" || ty_string == "Option<bool>" {
-        return true
+pub fn impl_into_rust(
+    project_info: &ProjectInfo,
+    input: &DeriveInput,
+    data: &ClassData,
+    errors: &CompileErrors,
+) -> TokenStream2 {
+    match data {
+        ClassData::Struct(data) => impl_struct_into_rust(input, data, errors),
+        ClassData::Enum(variants) => impl_enum_into_rust(input, variants, errors),
+        ClassData::Sealed(variants) => impl_sealed_into_rust(project_info, input, variants, errors),
     }
-    false
 }
 
-pub fn impl_into_rust(
+fn impl_struct_into_rust(
     input: &DeriveInput,
-    fields: &Vec<(syn::Ident, syn::Type)>,
+    data: &StructData,
     errors: &CompileErrors,
 ) -> TokenStream2 {
     let name = &input.ident;
 
-    // Call Java Getters
+    // Call Java Getters / record accessors / read public fields
     let mut fields_getters: TokenStream2 = quote! {};
-    for (name, ty) in fields.into_iter() {
-        let getter_fn_name = name.to_string();
-        let prefix = if is_bool_type(&ty) { "is" } else { "get" };
-
-        // Capital first
-        let getter_name = format!(
-            "\"{prefix}{}{}\"",
-            &getter_fn_name[0..1].to_uppercase(),
-            &getter_fn_name[1..]
-        );
-        let getter_name = ts2(&getter_name);
-        fields_getters.append_all(quote! {
-            #name: self.call_getter(#getter_name, env)?,
-        })
+    for field in data.fields.iter() {
+        let name = &field.ident;
+        if field.skip {
+            fields_getters.append_all(quote! {
+                #name: Default::default(),
+            });
+            continue;
+        }
+
+        let member = field.java_member(data.accessor);
+        let value = match field.accessor(data.accessor) {
+            JavaFFIAccessor::Getter | JavaFFIAccessor::Record => quote! { self.call_getter(#member, env)? },
+            JavaFFIAccessor::Field => quote! { java_bindgen::interop::get_field(&self, #member, env)? },
+        };
+        if field.default {
+            // null -> Default::default()
+            let ty = &field.ty;
+            fields_getters.append_all(quote! {
+                #name: { let value: Option<#ty> = #value; value.unwrap_or_default() },
+            });
+        } else {
+            fields_getters.append_all(quote! {
+                #name: #value,
+            });
+        }
     }
 
     quote! {
@@ -96,3 +104,127 @@ pub fn impl_into_rust(
 
     }
 }
+
+// Rust enum variant matched by Java enum name()
+fn impl_enum_into_rust(
+    input: &DeriveInput,
+    variants: &[syn::Ident],
+    errors: &CompileErrors,
+) -> TokenStream2 {
+    let name = &input.ident;
+
+    let mut variants_match = quote! {};
+    for variant in variants {
+        let variant_name = variant.to_string();
+        variants_match.append_all(quote! {
+            #variant_name => Ok(#name::#variant {}),
+        });
+    }
+
+    let unknown_variant = format!("Unknown {name} variant: ");
+
+    quote! {
+
+        #errors
+
+        impl<'local> java_bindgen::j2r::IntoRustType<'local, #name> for jni::objects::JObject<'local> {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> java_bindgen::JResult<#name> {
+                let variant: String = self.call_getter("name", env)?;
+                match variant.as_str() {
+                    #variants_match
+                    _ => java_bindgen::exception::JException::from_class_and_msg(
+                        java_bindgen::exception::JExceptionClass::IllegalArgumentException,
+                        &format!("{}{variant}", #unknown_variant),
+                    )
+                    .throw(env),
+                }
+            }
+        }
+
+        impl<'local> java_bindgen::prelude::IntoRustType<'local, #name> for jni::objects::JValueGen<jni::objects::JObject<'local>> {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> JResult<#name> {
+                let obj = self.l()?;
+                obj.into_rust(env)
+            }
+        }
+
+    }
+}
+
+// Rust enum variant matched by Java record class name (records are final)
+fn impl_sealed_into_rust(
+    project_info: &ProjectInfo,
+    input: &DeriveInput,
+    variants: &[EnumVariant],
+    errors: &CompileErrors,
+) -> TokenStream2 {
+    let name = &input.ident;
+
+    let mut variants_match = quote! {};
+    for variant in variants {
+        let variant_ident = &variant.ident;
+
+        // Call record accessors
+        let mut fields_getters = quote! {};
+        for (member, java_name, _) in variant.fields.iter() {
+            fields_getters.append_all(quote! {
+                #member: self.call_getter(#java_name, env)?,
+            });
+        }
+
+        // Class.getName() of the nested record (com.pkg.Shape$Circle)
+        let mut class_name = project_info.get_packages_path();
+        class_name.push(format!("{}${variant_ident}", common::java_class_name(input)));
+        let class_name = class_name.join(".");
+        variants_match.append_all(quote! {
+            #class_name => {
+                return Ok(#name::#variant_ident {
+                    #fields_getters
+                });
+            }
+        });
+    }
+
+    let null_value = format!("{name} is null");
+    let unknown_variant = format!("Unknown {name} variant");
+
+    quote! {
+
+        #errors
+
+        impl<'local> java_bindgen::j2r::IntoRustType<'local, #name> for jni::objects::JObject<'local> {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> java_bindgen::JResult<#name> {
+                if self.is_null() {
+                    return java_bindgen::exception::JException::from_class_and_msg(
+                        java_bindgen::exception::JExceptionClass::NullPointerException,
+                        #null_value,
+                    )
+                    .throw(env);
+                }
+
+                let class_name: String = env.with_local_frame(2, |env| -> java_bindgen::JResult<String> {
+                    let class = env.get_object_class(&self).j_catch_ini(env, "Failed to get object class")?;
+                    java_bindgen::interop::call_method(&class, "getName", (), env)
+                })?;
+                match class_name.as_str() {
+                    #variants_match
+                    _ => {}
+                }
+
+                java_bindgen::exception::JException::from_class_and_msg(
+                    java_bindgen::exception::JExceptionClass::IllegalArgumentException,
+                    #unknown_variant,
+                )
+                .throw(env)
+            }
+        }
+
+        impl<'local> java_bindgen::prelude::IntoRustType<'local, #name> for jni::objects::JValueGen<jni::objects::JObject<'local>> {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> JResult<#name> {
+                let obj = self.l()?;
+                obj.into_rust(env)
+            }
+        }
+
+    }
+}
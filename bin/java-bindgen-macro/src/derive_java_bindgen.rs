@@ -1,4 +1,5 @@
 use java_bindgen_core::{
+    cargo_parser::{IntegerPolicy, PanicPolicy},
     consts::ffi_definitions_path,
     ffi_store::{FFIStore, JavaFFIMethod},
     project_info::ProjectInfo,
@@ -9,9 +10,10 @@ use syn::{__private::TokenStream2, punctuated::Punctuated, spanned::Spanned, FnA
 
 use crate::{
     common::{
-        produce_java_args, produce_java_return, produce_rust_args_names, produce_rust_result_type,
+        self, produce_java_args, produce_java_return, produce_rust_args_names, produce_rust_error_type,
+        produce_rust_result_type,
     },
-    types_conversion::rewrite_rust_type_to_jni,
+    types_conversion::{find_primitive_slice, rewrite_rust_type_to_jni},
     util::{self, parse_attr_to_map, ts2, CompileErrors},
 };
 use crate::common::BindgenReturnType;
@@ -47,16 +49,21 @@ struct JavaFnSig {
     #[allow(dead_code)]
     class_indent: TokenStream2,
     into_rust_ident: Vec<TokenStream2>,
+    // (ident, &mut, u8 elements) borrowed primitive arrays
+    borrow_ident: Vec<(TokenStream2, bool, bool)>,
     jni_env_lifetime: TokenStream2,
 }
 
 // Rust fn arguments for inner function call
 fn produce_fn_java_args_signature(
     inputs: &Punctuated<FnArg, Token![,]>,
+    allow_receiver: bool,
+    policy: IntegerPolicy,
     errors: &mut CompileErrors,
 ) -> JavaFnSig {
     let jni_env_lifetime = extract_jni_env_lifetime(inputs, errors).unwrap_or(quote! { <'l> });
     let mut into_rust_ident = vec![];
+    let mut borrow_ident = vec![];
     let mut env_indent = quote! { env };
     let mut class_indent = quote! { _classs };
     let mut jni_env = quote! { mut #env_indent: JNIEnv #jni_env_lifetime };
@@ -66,19 +73,31 @@ fn produce_fn_java_args_signature(
     for (i, ele) in inputs.iter().enumerate() {
         match ele {
             FnArg::Receiver(r) => {
-                errors.add_spaned(
-                    r.span(),
-                    "'self' parameter is not supported. Use functions for defining java bindings."
-                        .into(),
-                );
-                args.append_all(quote! { self, #args });
+                if !allow_receiver {
+                    errors.add_spaned(
+                        r.span(),
+                        "'self' parameter is supported only in '#[java_bindgen] impl' blocks."
+                            .into(),
+                    );
+                }
+                // Java instance method (this)
+                class_indent = quote! { this };
+                jni_class = quote! { #class_indent: jni::objects::JObject #jni_env_lifetime };
             }
             FnArg::Typed(typed) => {
                 // Rewrite [Rust] to [RustJNI]
+                let rust_type = typed.ty.to_token_stream().to_string().replace(' ', "");
                 let ty = if let Some(ty) =
-                    rewrite_rust_type_to_jni(&typed.ty.to_token_stream(), &jni_env_lifetime, errors)
+                    rewrite_rust_type_to_jni(&typed.ty.to_token_stream(), &jni_env_lifetime, policy, errors)
                 {
-                    into_rust_ident.push(typed.pat.to_token_stream());
+                    match find_primitive_slice(&rust_type) {
+                        Some((element, _, _)) => borrow_ident.push((
+                            typed.pat.to_token_stream(),
+                            rust_type.contains("&mut"),
+                            *element == "u8",
+                        )),
+                        None => into_rust_ident.push(typed.pat.to_token_stream()),
+                    }
                     ty
                 } else {
                     typed.ty.to_token_stream()
@@ -127,13 +146,18 @@ fn produce_fn_java_args_signature(
         class_indent,
         jni_env_lifetime,
         into_rust_ident,
+        borrow_ident,
     }
 }
 
 // Macro attributes
-struct JavaBindgenAttr {
+pub(crate) struct JavaBindgenAttr {
     pub package: String,
+    // Java class name (fn: facade class, impl: handle class)
+    pub class: Option<String>,
     pub returns: Option<String>,
+    // throws = "java.io.IOException, com.acme.ImageException"
+    pub throws: Vec<String>,
 }
 
 impl JavaBindgenAttr {
@@ -141,15 +165,30 @@ impl JavaBindgenAttr {
         let map = parse_attr_to_map(attr);
         JavaBindgenAttr {
             package: map.get("package").unwrap_or(&"".to_string()).clone(),
+            class: map.get("class").filter(|c| !c.is_empty()).cloned(),
             returns: map
                 .get("return")
                 .cloned()
                 .or_else(|| map.get("returns").cloned()),
+            throws: map
+                .get("throws")
+                .map(|throws| {
+                    throws
+                        .split(',')
+                        .map(|class| class.trim().to_string())
+                        .filter(|class| !class.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
         }
     }
 }
 
 pub fn main(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if let Ok(java_impl) = syn::parse::<syn::ItemImpl>(item.clone()) {
+        return crate::derive_java_handle::main(attr, java_impl);
+    }
+
     let mut errors = CompileErrors::default();
 
     if let Ok(java_fn) = syn::parse::<syn::ItemFn>(item.clone()) {
@@ -170,104 +209,251 @@ pub fn main(attr: TokenStream, item: TokenStream) -> TokenStream {
             }
         };
         // Create project info
-        let project_info = ProjectInfo::from(&cargo_toml).set_package_name(&attribute.package);
+        let project_info = ProjectInfo::from(&cargo_toml);
+        let class_info = project_info.clone().set_package_name(&attribute.package);
         let rust_fn_name = java_fn.sig.ident.to_string();
-        let return_type = produce_rust_result_type(&java_fn.sig.output, &mut errors);
 
         // Safe FFI Methods
         if let Some(mut store) = FFIStore::read_from_file(&ffi_definitions_path(project_dir)) {
-            let args = produce_java_args(&java_fn.sig.inputs, &mut errors);
-            let return_type = attribute
-                .returns
-                .clone()
-                .unwrap_or_else(|| produce_java_return(return_type.as_token(), &mut errors));
-
+            let sig = produce_java_method_sig(
+                "public static native",
+                &java_fn.sig,
+                &attribute,
+                project_info.integer_policy,
+                &mut errors,
+            );
             store.add_ffi_method(JavaFFIMethod {
                 id: rust_fn_name.clone(),
-                sig: format!(
-                    "public static native {} {}({})",
-                    &return_type,
-                    &rust_fn_name,
-                    args.join(",")
-                ),
+                sig,
+                class: attribute.class.clone(),
+                package: Some(attribute.package.clone()).filter(|p| !p.is_empty()),
+                throws: produce_java_throws(&java_fn.sig, &attribute),
             });
             store.save();
         }
 
         // Rewrite rust function
-        let j_ffi_fn_name = format_ident!("{}", project_info.get_java_method_name(&rust_fn_name));
+        let j_ffi_fn_name = format_ident!(
+            "{}",
+            class_info.get_java_method_name(attribute.class.as_deref(), &rust_fn_name)
+        );
         let fn_name = java_fn.sig.ident.to_token_stream();
-        let args_names = produce_rust_args_names(&java_fn.sig.inputs, &mut errors);
-
-        let JavaFnSig {
-            args,
-            env_indent,
-            class_indent: _,
-            jni_env_lifetime,
-            into_rust_ident,
-        } = produce_fn_java_args_signature(&java_fn.sig.inputs, &mut errors);
-
-        // Input types conversion
-        let mut rewrites = quote! {};
-        for indent in into_rust_ident {
+        let ffi_fn = produce_ffi_fn(
+            &java_fn.sig,
+            &j_ffi_fn_name,
+            fn_name,
+            None,
+            &project_info,
+            &attribute,
+            &mut errors,
+        );
+
+        return quote! {
+
+            #errors
+
+            #[allow(non_snake_case)]
+            #source
+
+            #ffi_fn
+
+        }
+        .into();
+    }
+
+    item
+}
+
+// Java native method declaration
+pub(crate) fn produce_java_method_sig(
+    modifiers: &str,
+    sig: &syn::Signature,
+    attribute: &JavaBindgenAttr,
+    policy: IntegerPolicy,
+    errors: &mut CompileErrors,
+) -> String {
+    // return type errors are reported by produce_ffi_fn
+    let return_type = produce_rust_result_type(&sig.output, &mut CompileErrors::default());
+    let args = produce_java_args(&sig.inputs, policy, errors);
+    let return_type = attribute
+        .returns
+        .clone()
+        .unwrap_or_else(|| produce_java_return(return_type.as_token(), policy, errors));
+
+    format!("{modifiers} {} {}({})", &return_type, &sig.ident, args.join(","))
+}
+
+// java_bindgen error type (thrown with its own Java class, not declared)
+const J_EXCEPTION_PATHS: &[&str] = &[
+    "JException",
+    "exception::JException",
+    "java_bindgen::exception::JException",
+    "java_bindgen::prelude::JException",
+];
+
+// throws clause: attribute classes and the JResult<T, E> error type path (resolved by java-pack).
+// java-pack skips paths without a #[derive(JavaException)] class.
+pub(crate) fn produce_java_throws(sig: &syn::Signature, attribute: &JavaBindgenAttr) -> Vec<String> {
+    let mut throws = attribute.throws.clone();
+    throws.extend(produce_rust_error_type(&sig.output).filter(|ty| !J_EXCEPTION_PATHS.contains(&ty.as_str())));
+    throws
+}
+
+// Generate JNI function (extern "system")
+//
+// fn_call: function path (free function or associated function)
+// handle_type: Rust type owned by Java object (method with 'self' receiver)
+pub(crate) fn produce_ffi_fn(
+    sig: &syn::Signature,
+    j_ffi_fn_name: &syn::Ident,
+    fn_call: TokenStream2,
+    handle_type: Option<&Type>,
+    project_info: &ProjectInfo,
+    attribute: &JavaBindgenAttr,
+    errors: &mut CompileErrors,
+) -> TokenStream2 {
+    let return_type = produce_rust_result_type(&sig.output, errors);
+    let inputs: Punctuated<FnArg, Token![,]> = sig
+        .inputs
+        .iter()
+        .filter(|arg| !matches!(arg, FnArg::Receiver(_)))
+        .cloned()
+        .collect();
+    let args_names = produce_rust_args_names(&inputs, errors);
+
+    let JavaFnSig {
+        args,
+        env_indent,
+        class_indent,
+        jni_env_lifetime,
+        into_rust_ident,
+        borrow_ident,
+    } = produce_fn_java_args_signature(&sig.inputs, handle_type.is_some(), project_info.integer_policy, errors);
+    let policy = common::j_integer_policy(project_info.integer_policy);
+
+    // Rust memory returned as direct ByteBuffer (freed by Lib.DirectBufferCleaner).
+    // Entered by every function: JOwnedBuffer may be nested in returned classes or callback arguments.
+    let cleaner_class = format!("{}$DirectBufferCleaner", project_info.get_java_class_name());
+    let cleaner_class = common::class_path(project_info, cleaner_class);
+
+    // Integer policy and cleaner class of this crate for every conversion in the call
+    let mut rewrites = quote! {
+        let _integer_policy = #policy.enter();
+        let _cleaner_class = java_bindgen::buffer::enter_cleaner_class(#cleaner_class);
+    };
+
+    // Input types conversion
+    for indent in into_rust_ident {
+        rewrites.append_all(quote! {
+
+            let Ok(#indent) = #indent.into_rust(&mut #env_indent) else {
+                return Default::default()
+            };
+
+        });
+    }
+
+    // Borrow value owned by Java object
+    let fn_call = match handle_type {
+        Some(handle_type) => {
             rewrites.append_all(quote! {
 
-                let Ok(#indent) = #indent.into_rust(&mut #env_indent) else {
+                let Ok(mut __this) = java_bindgen::handle::borrow_handle::<#handle_type>(&mut #env_indent, &#class_indent) else {
                     return Default::default()
                 };
 
             });
+            quote! { __this.#fn_call }
         }
+        None => fn_call,
+    };
 
-        // Return type conversion
-        let jni_return_type = rewrite_rust_type_to_jni(return_type.as_token(), &jni_env_lifetime, &mut errors)
-            .unwrap_or_else(|| {
-                // Return JObject if custom type specified
-                if attribute.returns.is_some() {
-                    quote! { jni::objects::JObject #jni_env_lifetime }
-                } else {
-                    return_type.as_token().clone()
-                }
+    // Borrow Java array elements (released on drop)
+    for (indent, mutable, bytes) in borrow_ident {
+        rewrites.append_all(quote! {
+
+            let Ok(mut #indent) = java_bindgen::array::borrow_array_elements(&mut #env_indent, &#indent, #mutable) else {
+                return Default::default()
+            };
+
+        });
+        if bytes {
+            rewrites.append_all(match mutable {
+                true => quote! { let mut #indent = java_bindgen::array::as_bytes_mut(&mut #indent); },
+                false => quote! { let #indent = java_bindgen::array::as_bytes(&#indent); },
             });
+        }
+    }
 
-        let return_handler = match return_type {
-            BindgenReturnType::JResult(_) => {
-                quote! {
-                    java_bindgen::exception::j_result_handler(r, &mut #env_indent)
-                }
+
+    // Return type conversion
+    let jni_return_type = rewrite_rust_type_to_jni(return_type.as_token(), &jni_env_lifetime, project_info.integer_policy, errors)
+        .unwrap_or_else(|| {
+            // Return JObject if custom type specified
+            if attribute.returns.is_some() {
+                quote! { jni::objects::JObject #jni_env_lifetime }
+            } else {
+                return_type.as_token().clone()
             }
-            BindgenReturnType::Option(_) => {
-                quote! {
-                    java_bindgen::exception::option_handler(r, &mut #env_indent)
-                }
+        });
+
+    let return_handler = match return_type {
+        BindgenReturnType::JResult(_) => {
+            quote! {
+                java_bindgen::exception::j_result_handler(r, &mut #env_indent)
             }
-            BindgenReturnType::None(_) => {
-                quote! {
-                    Default::default()
-                }
+        }
+        BindgenReturnType::Option(_) => {
+            quote! {
+                java_bindgen::exception::option_handler(r, &mut #env_indent)
             }
-        };
-
-        return quote! {
-
-            #errors
+        }
+        BindgenReturnType::None(_) => {
+            quote! {
+                Default::default()
+            }
+        }
+    };
 
-            #[allow(non_snake_case)]
-            #source
+    let body = produce_panic_guard(
+        project_info,
+        &env_indent,
+        quote! {
 
-            #[no_mangle]
-            #[allow(unused_mut, non_snake_case, unused_variables)]
-            pub extern "system" fn #j_ffi_fn_name #jni_env_lifetime(#args) -> #jni_return_type {
+            #rewrites
 
-                #rewrites
+            let r = #fn_call(#args_names);
+            #return_handler
+        },
+    );
 
-                let r = #fn_name(#args_names);
-                #return_handler
-            }
+    quote! {
 
+        #[no_mangle]
+        #[allow(unused_mut, non_snake_case, unused_variables)]
+        pub extern "system" fn #j_ffi_fn_name #jni_env_lifetime(#args) -> #jni_return_type {
+            #body
         }
-        .into();
+
     }
+}
 
-    item
+// Run JNI function body with catch_unwind (panic must not unwind into the JVM)
+pub(crate) fn produce_panic_guard(project_info: &ProjectInfo, env_indent: &TokenStream2, body: TokenStream2) -> TokenStream2 {
+    let panic_class = common::class_path(project_info, "RustPanicException".to_string());
+    let panic_handler = match project_info.panic_policy {
+        PanicPolicy::Throw => quote! {
+            java_bindgen::exception::j_panic_handler(result, #panic_class, &mut #env_indent)
+        },
+        PanicPolicy::Abort => quote! {
+            result.unwrap_or_else(|_| std::process::abort())
+        },
+    };
+
+    quote! {
+        let result = java_bindgen::exception::j_catch_panic(|| {
+            #body
+        });
+        #panic_handler
+    }
 }
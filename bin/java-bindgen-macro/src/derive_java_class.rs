@@ -1,11 +1,7 @@
-use java_bindgen_core::{
-    consts::ffi_definitions_path,
-    ffi_store::{FFIStore, JavaFFIClass},
-    project_info::ProjectInfo,
-};
+use java_bindgen_core::project_info::ProjectInfo;
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput};
+use syn::DeriveInput;
 
 use crate::util::CompileErrors;
 
@@ -14,12 +10,6 @@ pub fn main(item: TokenStream) -> TokenStream {
         let project_dir = std::path::Path::new(".");
         let mut errors = CompileErrors::default();
 
-        // Struct Guard
-        let Data::Struct(ref struct_info) = input.data else {
-            errors.add("Only struct is allowed.".to_string());
-            return errors.into();
-        };
-
         // Parse Cargo.toml file
         let cargo_toml = match crate::util::parse_project_toml(project_dir) {
             Ok(toml) => toml,
@@ -29,23 +19,13 @@ pub fn main(item: TokenStream) -> TokenStream {
         };
 
         // Create project info
-        let project_info = ProjectInfo::from(&cargo_toml);
-        let fields = crate::common::get_struct_fileds(&struct_info.fields, &mut errors);
-        let Some(java_fields) = crate::common::produce_java_class_ffi_types(&fields, &mut errors)
-        else {
+        let project_info = crate::common::class_project_info(ProjectInfo::from(&cargo_toml), &input);
+        let Some(data) = crate::common::register_java_class(&project_info, &input, &mut errors) else {
             return errors.into();
         };
 
-        if let Some(mut store) = FFIStore::read_from_file(&ffi_definitions_path(project_dir)) {
-            store.add_ffi_class(JavaFFIClass {
-                id: input.ident.to_string(),
-                fields: java_fields,
-            });
-            store.save();
-        }
-
-        let into_java = crate::dervie_into_java::impl_into_java(&project_info, &input, &fields, &errors);
-        let into_rust = crate::derive_into_rust::impl_into_rust(&input, &fields, &errors);
+        let into_java = crate::dervie_into_java::impl_into_java(&project_info, &input, &data, &errors);
+        let into_rust = crate::derive_into_rust::impl_into_rust(&project_info, &input, &data, &errors);
         let java_type = crate::dervie_java_type::impl_java_type(&project_info, &input, &errors);
 
         return quote! {
@@ -0,0 +1,90 @@
+use java_bindgen_core::{
+    consts::ffi_definitions_path,
+    ffi_store::{FFIStore, JavaFFIClass, JavaFFIClassKind, JavaFFIVariant},
+    project_info::ProjectInfo,
+};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::{common, util::CompileErrors};
+
+// Rust error -> Java exception class (enum variants as nested subclasses)
+pub fn main(item: TokenStream) -> TokenStream {
+    let Ok(input) = syn::parse::<DeriveInput>(item) else {
+        return TokenStream::default();
+    };
+    let project_dir = std::path::Path::new(".");
+    let mut errors = CompileErrors::default();
+
+    // Parse Cargo.toml file
+    let cargo_toml = match crate::util::parse_project_toml(project_dir) {
+        Ok(toml) => toml,
+        Err(err) => {
+            return crate::util::error(input.ident.span(), err.to_string()).into();
+        }
+    };
+
+    // Create project info
+    let project_info = common::class_project_info(ProjectInfo::from(&cargo_toml), &input);
+    let attr = common::parse_java_attrs(&input.attrs, &["package", "name", "extends"], &mut errors);
+    let class_name = common::java_class_name(&input);
+
+    let mut variants = vec![];
+    let class_path = match input.data {
+        syn::Data::Struct(_) => {
+            let class_path = common::class_path(&project_info, class_name.clone());
+            quote! { #class_path }
+        }
+        syn::Data::Enum(ref enum_info) => {
+            let mut arms = quote! {};
+            for variant in enum_info.variants.iter() {
+                let variant_ident = &variant.ident;
+                let variant_name = common::parse_java_attrs(&variant.attrs, &["name"], &mut errors)
+                    .remove("name")
+                    .unwrap_or_else(|| variant_ident.to_string());
+                let class_path = common::class_path(&project_info, format!("{class_name}${variant_name}"));
+                arms.extend(quote! { Self::#variant_ident { .. } => #class_path, });
+                variants.push(JavaFFIVariant {
+                    id: variant_name,
+                    ..Default::default()
+                });
+            }
+            quote! {
+                match self {
+                    #arms
+                }
+            }
+        }
+        syn::Data::Union(_) => {
+            errors.add("Only struct or enum is allowed.".to_string());
+            return errors.into();
+        }
+    };
+
+    if let Some(mut store) = FFIStore::read_from_file(&ffi_definitions_path(project_dir)) {
+        store.add_ffi_class(JavaFFIClass {
+            id: input.ident.to_string(),
+            name: attr.get("name").cloned(),
+            package: attr.get("package").cloned(),
+            kind: JavaFFIClassKind::Exception,
+            variants,
+            extends: attr.get("extends").cloned(),
+            ..Default::default()
+        });
+        store.save();
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        #errors
+
+        impl #impl_generics java_bindgen::exception::JavaException for #name #ty_generics #where_clause {
+            fn java_class(&self) -> String {
+                #class_path.to_string()
+            }
+        }
+    }
+    .into()
+}
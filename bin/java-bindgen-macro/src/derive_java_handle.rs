@@ -0,0 +1,196 @@
+use java_bindgen_core::{
+    consts::ffi_definitions_path,
+    ffi_store::{FFIStore, JavaFFIClass, JavaFFIClassKind, JavaFFIMethod},
+    project_info::ProjectInfo,
+};
+use proc_macro::TokenStream;
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
+use syn::{spanned::Spanned, ImplItem, ItemImpl, Visibility};
+
+use crate::{
+    common,
+    derive_java_bindgen::{produce_ffi_fn, produce_java_method_sig, produce_java_throws, produce_panic_guard, JavaBindgenAttr},
+    util::{self, CompileErrors},
+};
+
+// Rust struct owned by Java object (#[java_bindgen] impl Type {})
+pub fn main(attr: TokenStream, java_impl: ItemImpl) -> TokenStream {
+    let mut errors = CompileErrors::default();
+    let source = java_impl.to_token_stream();
+    let attribute = JavaBindgenAttr::parse_attr(attr);
+    let project_dir = std::path::Path::new(".");
+
+    // Impl Guard
+    let class_name = match *java_impl.self_ty {
+        syn::Type::Path(ref path) if java_impl.trait_.is_none() && java_impl.generics.params.is_empty() => {
+            path.path.segments.last().map(|s| s.ident.to_string())
+        }
+        _ => None,
+    };
+    let Some(class_name) = class_name else {
+        errors.add_spaned(
+            java_impl.span(),
+            "Only 'impl Type {}' blocks (no traits, no generics) are supported.".to_string(),
+        );
+        return quote! {
+            #source
+            #errors
+        }
+        .into();
+    };
+
+    // Parse Cargo.toml file
+    let cargo_toml = match util::parse_project_toml(project_dir) {
+        Ok(toml) => toml,
+        Err(err) => {
+            let error = util::error(java_impl.span(), err.to_string());
+            return quote! {
+                #source
+                #error
+            }
+            .into();
+        }
+    };
+
+    // Create project info
+    let project_info = ProjectInfo::from(&cargo_toml);
+    let class_info = project_info.clone().set_package_name(&attribute.package);
+    let rust_name = class_name;
+    let class_name = attribute.class.clone().unwrap_or_else(|| rust_name.clone());
+    let self_ty = &java_impl.self_ty;
+    let self_ty_str = self_ty.to_token_stream().to_string();
+
+    // Public methods -> Java native methods
+    let mut java_methods = vec![];
+    let mut ffi_fns = quote! {};
+    for item in java_impl.items.iter() {
+        let ImplItem::Fn(method) = item else {
+            continue;
+        };
+        if !matches!(method.vis, Visibility::Public(_)) {
+            continue;
+        }
+
+        let method_name = method.sig.ident.to_string();
+        if method_name == "close" {
+            errors.add_spaned(
+                method.sig.ident.span(),
+                "'close' is reserved (AutoCloseable). Rust value is dropped on close().".to_string(),
+            );
+            continue;
+        }
+
+        let sig_tokens = util::replace_self_type(&method.sig.to_token_stream(), &self_ty_str);
+        let Ok(sig) = syn::parse2::<syn::Signature>(sig_tokens) else {
+            errors.add_spaned(method.sig.span(), "Unsupported method signature.".to_string());
+            continue;
+        };
+
+        let handle_type = match sig.receiver() {
+            Some(receiver) if receiver.reference.is_none() => {
+                errors.add_spaned(
+                    receiver.span(),
+                    "Use '&self' or '&mut self'. Rust value is owned by Java object.".to_string(),
+                );
+                continue;
+            }
+            Some(_) => Some(self_ty.as_ref()),
+            None => None,
+        };
+
+        let modifiers = if handle_type.is_some() {
+            "public synchronized native"
+        } else {
+            "public static native"
+        };
+        java_methods.push(JavaFFIMethod {
+            id: method_name.clone(),
+            sig: produce_java_method_sig(modifiers, &sig, &attribute, project_info.integer_policy, &mut errors),
+            throws: produce_java_throws(&sig, &attribute),
+            ..Default::default()
+        });
+
+        let j_ffi_fn_name = format_ident!(
+            "{}",
+            class_info.get_java_class_method_name(&class_name, &method_name)
+        );
+        let fn_name = &sig.ident;
+        let fn_call = match handle_type {
+            Some(_) => quote! { #fn_name },
+            None => quote! { <#self_ty>::#fn_name },
+        };
+        ffi_fns.append_all(produce_ffi_fn(
+            &sig,
+            &j_ffi_fn_name,
+            fn_call,
+            handle_type,
+            &project_info,
+            &attribute,
+            &mut errors,
+        ));
+    }
+
+    if let Some(mut store) = FFIStore::read_from_file(&ffi_definitions_path(project_dir)) {
+        store.add_ffi_class(JavaFFIClass {
+            id: rust_name.clone(),
+            name: attribute.class.clone(),
+            package: Some(attribute.package.clone()).filter(|p| !p.is_empty()),
+            kind: JavaFFIClassKind::Handle,
+            methods: java_methods,
+            ..Default::default()
+        });
+        store.save();
+    }
+
+    let class_path = common::class_path(&class_info, class_name.clone());
+    let close_fn_name = format_ident!(
+        "{}",
+        class_info.get_java_class_method_name(&class_name, "close")
+    );
+
+    let close_body = produce_panic_guard(
+        &project_info,
+        &quote! { env },
+        quote! { java_bindgen::handle::drop_handle::<#self_ty>(&mut env, &this).ok(); },
+    );
+
+    quote! {
+
+        #errors
+
+        #source
+
+        impl<'local> java_bindgen::r2j::IntoJavaType<'local, jni::objects::JObject<'local>> for #self_ty {
+            fn into_java(self, env: &mut jni::JNIEnv<'local>) -> java_bindgen::JResult<jni::objects::JObject<'local>> {
+                java_bindgen::handle::new_handle_object(env, #class_path, self)
+            }
+        }
+
+        impl<'local> java_bindgen::interop::JTypeInfo<'local> for #self_ty {
+            fn j_type() -> jni::signature::JavaType {
+                jni::signature::JavaType::Object(#class_path.to_string())
+            }
+
+            fn j_return_type() -> jni::signature::ReturnType {
+                jni::signature::ReturnType::Object
+            }
+
+            fn into_j_value(self, env: &mut jni::JNIEnv<'local>) -> java_bindgen::JResult<jni::objects::JValueOwned<'local>> {
+                let obj = self.into_java(env)?;
+                Ok(jni::objects::JValueOwned::Object(obj))
+            }
+        }
+
+        impl java_bindgen::interop::JObjectArrayElement for #self_ty {}
+
+        #ffi_fns
+
+        #[no_mangle]
+        #[allow(non_snake_case)]
+        pub extern "system" fn #close_fn_name<'local>(mut env: jni::JNIEnv<'local>, this: jni::objects::JObject<'local>) {
+            #close_body
+        }
+
+    }
+    .into()
+}
@@ -0,0 +1,214 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{spanned::Spanned, FnArg, ItemTrait, Pat, TraitItem};
+use syn::__private::TokenStream2;
+
+use crate::util::{parse_attr_to_map, CompileErrors};
+
+// Java object proxy (#[java_interface(class = "com.acme.Repo")] trait Repo {})
+pub fn main(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut errors = CompileErrors::default();
+    let Ok(java_trait) = syn::parse::<ItemTrait>(item.clone()) else {
+        errors.add("Only trait is allowed.".to_string());
+        let item = TokenStream2::from(item);
+        return quote! {
+            #item
+            #errors
+        }
+        .into();
+    };
+
+    let source = java_trait.to_token_stream();
+    let attr = parse_attr_to_map(attr);
+    let Some(class_name) = attr.get("class").filter(|c| !c.is_empty()) else {
+        errors.add_spaned(
+            java_trait.ident.span(),
+            "Add Java class: #[java_interface(class = \"com.java.package.Interface\")]".to_string(),
+        );
+        return quote! {
+            #source
+            #errors
+        }
+        .into();
+    };
+
+    let class_path = class_name.replace('.', "/");
+    let trait_name = &java_trait.ident;
+    let proxy_name = format_ident!("J{}", trait_name);
+    let vis = &java_trait.vis;
+
+    let mut methods = quote! {};
+    for item in java_trait.items.iter() {
+        let TraitItem::Fn(method) = item else {
+            continue;
+        };
+        // default implementation
+        if method.default.is_some() {
+            continue;
+        }
+        if let Some(body) = produce_proxy_method(method, &mut errors) {
+            methods.extend(body);
+        }
+    }
+
+    quote! {
+        #source
+
+        #errors
+
+        #vis struct #proxy_name {
+            object: jni::objects::GlobalRef,
+        }
+
+        impl #proxy_name {
+            pub const CLASS_PATH: &'static str = #class_path;
+
+            pub fn as_obj(&self) -> &jni::objects::JObject<'static> {
+                self.object.as_obj()
+            }
+        }
+
+        impl #trait_name for #proxy_name {
+            #methods
+        }
+
+        impl<'local> java_bindgen::j2r::IntoRustType<'local, #proxy_name> for jni::objects::JObject<'local> {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> java_bindgen::JResult<#proxy_name> {
+                let object = java_bindgen::proxy::j_proxy_object(self, #class_path, env)?;
+                Ok(#proxy_name { object })
+            }
+        }
+
+        impl<'local> java_bindgen::j2r::IntoRustType<'local, #proxy_name> for jni::objects::JValueGen<jni::objects::JObject<'local>> {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> java_bindgen::JResult<#proxy_name> {
+                use java_bindgen::exception::JavaCatchINI;
+                let obj = self.l().j_catch_ini(env, concat!("Cast failed [JObject -> ", #class_name, "]"))?;
+                obj.into_rust(env)
+            }
+        }
+
+        impl<'local> java_bindgen::r2j::IntoJavaType<'local, jni::objects::JObject<'local>> for #proxy_name {
+            fn into_java(self, env: &mut jni::JNIEnv<'local>) -> java_bindgen::JResult<jni::objects::JObject<'local>> {
+                use java_bindgen::exception::JavaCatchINI;
+                env.new_local_ref(self.object.as_obj()).j_catch_ini(env, "Failed to create JObject")
+            }
+        }
+
+        impl<'local> java_bindgen::interop::JTypeInfo<'local> for #proxy_name {
+            fn j_type() -> jni::signature::JavaType {
+                jni::signature::JavaType::Object(#class_path.to_string())
+            }
+
+            fn j_return_type() -> jni::signature::ReturnType {
+                jni::signature::ReturnType::Object
+            }
+
+            fn into_j_value(self, env: &mut jni::JNIEnv<'local>) -> java_bindgen::JResult<jni::objects::JValueOwned<'local>> {
+                use java_bindgen::r2j::IntoJavaType;
+                Ok(jni::objects::JValueOwned::Object(self.into_java(env)?))
+            }
+        }
+
+        impl java_bindgen::interop::JObjectArrayElement for #proxy_name {}
+    }
+    .into()
+}
+
+// fn find_name(&self, env: &mut JNIEnv<'a>, id: i64) -> JResult<String>
+fn produce_proxy_method(method: &syn::TraitItemFn, errors: &mut CompileErrors) -> Option<TokenStream2> {
+    let sig = &method.sig;
+    let usage = "Use: fn name(&self, env: &mut JNIEnv<'a>, arg: T) -> JResult<R>";
+
+    let mut receiver = false;
+    let mut env = None;
+    let mut arg_names = vec![];
+    let mut arg_types = vec![];
+    for arg in sig.inputs.iter() {
+        match arg {
+            FnArg::Receiver(r) if r.reference.is_some() && r.mutability.is_none() => receiver = true,
+            FnArg::Typed(arg) => {
+                let Pat::Ident(ref name) = *arg.pat else {
+                    errors.add_spaned(arg.pat.span(), "Only named arguments are supported.".to_string());
+                    return None;
+                };
+                if arg.ty.to_token_stream().to_string().contains("JNIEnv") {
+                    env = Some(&name.ident);
+                } else {
+                    arg_names.push(&name.ident);
+                    arg_types.push(&arg.ty);
+                }
+            }
+            _ => {
+                errors.add_spaned(arg.span(), format!("Only '&self' receiver is supported. {usage}"));
+                return None;
+            }
+        }
+    }
+    let (true, Some(env)) = (receiver, env) else {
+        errors.add_spaned(sig.span(), format!("Add '&self' and 'env: &mut JNIEnv' arguments. {usage}"));
+        return None;
+    };
+    let Some(return_type) = result_type(&sig.output) else {
+        errors.add_spaned(sig.output.span(), format!("Return JResult<R>. {usage}"));
+        return None;
+    };
+
+    let java_name = to_java_method_name(&sig.ident.to_string());
+    Some(quote! {
+        #sig {
+            use java_bindgen::interop::{JTypeInfo, TypeSignatureBuilder};
+            let sig = java_bindgen::signature_by_type!(#(#arg_types),* => #return_type);
+            let args = vec![#(#arg_names.into_j_value(#env)?),*];
+            java_bindgen::proxy::j_proxy_call(self.as_obj(), #java_name, sig, args, #env)
+        }
+    })
+}
+
+// T from JResult<T> / Result<T, E>
+fn result_type(output: &syn::ReturnType) -> Option<TokenStream2> {
+    let syn::ReturnType::Type(_, ty) = output else {
+        return None;
+    };
+    let syn::Type::Path(path) = ty.as_ref() else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "JResult" && segment.ident != "Result" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(ty) => Some(ty.to_token_stream()),
+        _ => None,
+    }
+}
+
+// find_by_name -> findByName
+fn to_java_method_name(name: &str) -> String {
+    let mut result = String::new();
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' && !result.is_empty() {
+            upper = true;
+        } else if upper {
+            result.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn should_convert_method_name() {
+        assert_eq!("findByName", super::to_java_method_name("find_by_name"));
+        assert_eq!("size", super::to_java_method_name("size"));
+        assert_eq!("getX2", super::to_java_method_name("get_x2"));
+    }
+}
@@ -1,15 +1,11 @@
-use java_bindgen_core::{
-    consts::ffi_definitions_path,
-    ffi_store::{FFIStore, JavaFFIClass},
-    project_info::ProjectInfo,
-};
+use java_bindgen_core::{ffi_store::JavaFFIAccessor, project_info::ProjectInfo};
 use proc_macro::TokenStream;
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
-use syn::{Data, DeriveInput};
+use syn::DeriveInput;
 use syn::__private::TokenStream2;
 
 use crate::{
-    common,
+    common::{self, ClassData, EnumVariant, StructData, StructField},
     util::{self, CompileErrors},
 };
 
@@ -18,12 +14,6 @@ pub fn main(item: TokenStream) -> TokenStream {
         let project_dir = std::path::Path::new(".");
         let mut errors = CompileErrors::default();
 
-        // Struct Guard
-        let Data::Struct(ref struct_info) = input.data else {
-            errors.add("Only struct is allowed.".to_string());
-            return errors.into();
-        };
-
         // Parse Cargo.toml file
         let cargo_toml = match util::parse_project_toml(project_dir) {
             Ok(toml) => toml,
@@ -33,22 +23,12 @@ pub fn main(item: TokenStream) -> TokenStream {
         };
 
         // Create project info
-        let project_info = ProjectInfo::from(&cargo_toml);
-        let fields = crate::common::get_struct_fileds(&struct_info.fields, &mut errors);
-        let Some(java_fields) = crate::common::produce_java_class_ffi_types(&fields, &mut errors)
-        else {
+        let project_info = crate::common::class_project_info(ProjectInfo::from(&cargo_toml), &input);
+        let Some(data) = common::register_java_class(&project_info, &input, &mut errors) else {
             return errors.into();
         };
 
-        if let Some(mut store) = FFIStore::read_from_file(&ffi_definitions_path(project_dir)) {
-            store.add_ffi_class(JavaFFIClass {
-                id: input.ident.to_string(),
-                fields: java_fields,
-            });
-            store.save();
-        }
-
-        return impl_into_java(&project_info, &input, &fields, &errors).into();
+        return impl_into_java(&project_info, &input, &data, &errors).into();
     }
 
     TokenStream::default()
@@ -57,16 +37,33 @@ pub fn main(item: TokenStream) -> TokenStream {
 pub fn impl_into_java(
     project_info: &ProjectInfo,
     input: &DeriveInput,
-    fields: &Vec<(syn::Ident, syn::Type)>,
+    data: &ClassData,
+    errors: &CompileErrors,
+) -> TokenStream2 {
+    match data {
+        ClassData::Struct(data) => impl_struct_into_java(project_info, input, data, errors),
+        ClassData::Enum(variants) => impl_enum_into_java(project_info, input, variants, errors),
+        ClassData::Sealed(variants) => impl_sealed_into_java(project_info, input, variants, errors),
+    }
+}
+
+fn impl_struct_into_java(
+    project_info: &ProjectInfo,
+    input: &DeriveInput,
+    data: &StructData,
     errors: &CompileErrors,
 ) -> TokenStream2 {
+    if data.accessor == JavaFFIAccessor::Field {
+        return impl_fields_into_java(project_info, input, data, errors);
+    }
+
     let name = &input.ident;
 
     // rust to java type covertion
     let mut type_signature = quote! {};
     let mut args_conversion = quote! {};
     let mut args_list = quote! {};
-    for (i, (name, ty)) in fields.into_iter().enumerate() {
+    for (i, StructField { ident: name, ty, .. }) in data.java_fields().enumerate() {
         let arg_name = format_ident!("a{i}");
 
         // ',' sepparated Types
@@ -87,7 +84,7 @@ pub fn impl_into_java(
         args_list.append_all(quote! {#arg_name.borrow()});
     }
 
-    let class_path = common::class_path(&project_info, name.to_string());
+    let class_path = common::class_path(project_info, common::java_class_name(input));
     let (_, ty_generics, where_clause) = input.generics.split_for_impl();
 
     quote! {
@@ -100,8 +97,156 @@ pub fn impl_into_java(
 
                 #args_conversion
 
-                let class = env.find_class(#class_path).j_catch(env)?;
-                env.new_object(class, sig.to_string(), &[#args_list]).j_catch(env)
+                let class = env.find_class(#class_path).j_catch_ini(env, concat!(#class_path, " class not found"))?;
+                env.new_object(class, sig.to_string(), &[#args_list])
+                    .j_catch_ini(env, concat!("Failed to create ", #class_path))
+            }
+        }
+
+    }
+}
+
+// Java class with public fields: no-args constructor, then fields assigned one by one
+fn impl_fields_into_java(
+    project_info: &ProjectInfo,
+    input: &DeriveInput,
+    data: &StructData,
+    errors: &CompileErrors,
+) -> TokenStream2 {
+    let name = &input.ident;
+
+    let mut fields_setters = quote! {};
+    for field in data.java_fields() {
+        let member = &field.ident;
+        let java_field = field.java_field(data.accessor);
+        fields_setters.append_all(quote! {
+            java_bindgen::interop::set_field(&obj, #java_field, self.#member, env)?;
+        });
+    }
+
+    let class_path = common::class_path(project_info, common::java_class_name(input));
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+
+        #errors
+
+        impl <'local> java_bindgen::r2j::IntoJavaType<'local, jni::objects::JObject<'local>> for #name #ty_generics #where_clause {
+            fn into_java(self, env: &mut jni::JNIEnv<'local>) -> java_bindgen::JResult<jni::objects::JObject<'local>> {
+                let class = env.find_class(#class_path).j_catch_ini(env, concat!(#class_path, " class not found"))?;
+                let obj = env.new_object(class, "()V", &[])
+                    .j_catch_ini(env, concat!("Failed to create ", #class_path))?;
+
+                #fields_setters
+
+                Ok(obj)
+            }
+        }
+
+    }
+}
+
+// Java enum constant resolved by name (Enum.valueOf)
+fn impl_enum_into_java(
+    project_info: &ProjectInfo,
+    input: &DeriveInput,
+    variants: &[syn::Ident],
+    errors: &CompileErrors,
+) -> TokenStream2 {
+    let name = &input.ident;
+
+    let mut variants_names = quote! {};
+    for variant in variants {
+        let variant_name = variant.to_string();
+        variants_names.append_all(quote! {
+            #name::#variant { .. } => #variant_name,
+        });
+    }
+
+    let class_path = common::class_path(project_info, common::java_class_name(input));
+
+    quote! {
+
+        #errors
+
+        impl <'local> java_bindgen::r2j::IntoJavaType<'local, jni::objects::JObject<'local>> for #name {
+            fn into_java(self, env: &mut jni::JNIEnv<'local>) -> java_bindgen::JResult<jni::objects::JObject<'local>> {
+                let variant = match self {
+                    #variants_names
+                };
+
+                let sig = format!("(Ljava/lang/String;)L{};", #class_path);
+                let class = env.find_class(#class_path).j_catch_ini(env, concat!(#class_path, " class not found"))?;
+                let variant = env.new_string(variant).j_catch_ini(env, "Failed to create String")?;
+                env.call_static_method(class, "valueOf", sig, &[jni::objects::JValue::Object(&variant)])
+                    .and_then(|v| v.l())
+                    .j_catch_ini(env, concat!(#class_path, ".valueOf() failed"))
+            }
+        }
+
+    }
+}
+
+// Java record (nested in sealed interface) created by canonical constructor
+fn impl_sealed_into_java(
+    project_info: &ProjectInfo,
+    input: &DeriveInput,
+    variants: &[EnumVariant],
+    errors: &CompileErrors,
+) -> TokenStream2 {
+    let name = &input.ident;
+
+    let mut variants_conversion = quote! {};
+    for variant in variants {
+        let variant_ident = &variant.ident;
+        let mut type_signature = quote! {};
+        let mut bindings = quote! {};
+        let mut args_conversion = quote! {};
+        let mut args_list = quote! {};
+        for (i, (member, _, ty)) in variant.fields.iter().enumerate() {
+            let arg_name = format_ident!("a{i}");
+
+            if !type_signature.is_empty() {
+                type_signature.append_all(quote! {, })
+            }
+            type_signature.append_all(ty.to_token_stream());
+
+            bindings.append_all(quote! { #member: #arg_name, });
+
+            args_conversion.append_all(quote! {
+                let #arg_name = #arg_name.into_j_value(env)?;
+            });
+
+            if !args_list.is_empty() {
+                args_list.append_all(quote! {, })
+            }
+            args_list.append_all(quote! {#arg_name.borrow()});
+        }
+
+        let class_name = format!("{}${variant_ident}", common::java_class_name(input));
+        let class_path = common::class_path(project_info, class_name);
+        variants_conversion.append_all(quote! {
+            #name::#variant_ident { #bindings } => {
+                let sig = signature_by_type!(#type_signature => JVoid);
+
+                #args_conversion
+
+                let class = env.find_class(#class_path).j_catch_ini(env, concat!(#class_path, " class not found"))?;
+                env.new_object(class, sig.to_string(), &[#args_list])
+                    .j_catch_ini(env, concat!("Failed to create ", #class_path))
+            }
+        });
+    }
+
+    quote! {
+
+        #errors
+
+        impl <'local> java_bindgen::r2j::IntoJavaType<'local, jni::objects::JObject<'local>> for #name {
+            fn into_java(self, env: &mut jni::JNIEnv<'local>) -> java_bindgen::JResult<jni::objects::JObject<'local>> {
+                match self {
+                    #variants_conversion
+                }
             }
         }
 
@@ -1,11 +1,7 @@
-use java_bindgen_core::{
-    consts::ffi_definitions_path,
-    ffi_store::{FFIStore, JavaFFIClass},
-    project_info::ProjectInfo,
-};
+use java_bindgen_core::project_info::ProjectInfo;
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput};
+use syn::DeriveInput;
 use syn::__private::TokenStream2;
 
 use crate::util::{self, CompileErrors};
@@ -15,12 +11,6 @@ pub fn main(item: TokenStream) -> TokenStream {
         let project_dir = std::path::Path::new(".");
         let mut errors = CompileErrors::default();
 
-        // Struct Guard
-        let Data::Struct(ref struct_info) = input.data else {
-            errors.add("Only struct is allowed.".to_string());
-            return errors.into();
-        };
-
         // Parse Cargo.toml file
         let cargo_toml = match util::parse_project_toml(project_dir) {
             Ok(toml) => toml,
@@ -30,19 +20,9 @@ pub fn main(item: TokenStream) -> TokenStream {
         };
 
         // Create project info
-        let project_info = ProjectInfo::from(&cargo_toml);
-        let fields = crate::common::get_struct_fileds(&struct_info.fields, &mut errors);
-        let Some(java_fields) = crate::common::produce_java_class_ffi_types(&fields, &mut errors)
-        else {
+        let project_info = crate::common::class_project_info(ProjectInfo::from(&cargo_toml), &input);
+        if crate::common::register_java_class(&project_info, &input, &mut errors).is_none() {
             return errors.into();
-        };
-
-        if let Some(mut store) = FFIStore::read_from_file(&ffi_definitions_path(project_dir)) {
-            store.add_ffi_class(JavaFFIClass {
-                id: input.ident.to_string(),
-                fields: java_fields,
-            });
-            store.save();
         }
 
         return impl_java_type(&project_info, &input, &errors).into();
@@ -58,7 +38,7 @@ pub fn impl_java_type(
 ) -> TokenStream2 {
     let name = &input.ident;
     let (_, ty_generics, where_clause) = input.generics.split_for_impl();
-    let class_path = crate::common::class_path(&project_info, name.to_string());
+    let class_path = crate::common::class_path(project_info, crate::common::java_class_name(input));
     quote! {
 
         #errors
@@ -78,5 +58,7 @@ pub fn impl_java_type(
             }
         }
 
+        impl java_bindgen::interop::JObjectArrayElement for #name #ty_generics #where_clause {}
+
     }
 }
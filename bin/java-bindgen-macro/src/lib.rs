@@ -9,50 +9,67 @@ mod util;
 #[doc(hidden)]
 mod common;
 mod derive_java_bindgen;
+mod derive_java_handle;
+mod derive_java_interface;
 mod dervie_into_java;
 mod derive_into_rust;
 mod dervie_java_type;
 mod derive_java_class;
+mod derive_java_exception;
 mod derive_jlogger;
 mod derive_test_jvm;
 mod types_conversion;
 
 // Generate FFI for Java Native Interface (JNI)
+// fn: static native method
+// impl: Java class owning Rust value (instance native methods)
 #[proc_macro_attribute]
 pub fn java_bindgen(attr: TokenStream, item: TokenStream) -> TokenStream {
     derive_java_bindgen::main(attr, item)
 }
 
 // Convert Rust (struct) to JObject (class)
-#[proc_macro_derive(IntoJava)]
+#[proc_macro_derive(IntoJava, attributes(java))]
 pub fn java_bindgen_into_java(item: TokenStream) -> TokenStream {
     dervie_into_java::main(item)
 }
 
 // Convert JObject (class) to Rust (struct)
-#[proc_macro_derive(IntoRust)]
+#[proc_macro_derive(IntoRust, attributes(java))]
 pub fn java_bindgen_into_rust(item: TokenStream) -> TokenStream {
     derive_into_rust::main(item)
 }
 
 // Deliver JavaTypeInfo Metadata
-#[proc_macro_derive(JavaType)]
+#[proc_macro_derive(JavaType, attributes(java))]
 pub fn java_bindgen_java_type(item: TokenStream) -> TokenStream {
     dervie_java_type::main(item)
 }
 
 // Combines: IntoRust, IntoJava, JavaType
-#[proc_macro_derive(JavaClass)]
+#[proc_macro_derive(JavaClass, attributes(java))]
 pub fn java_bindgen_java_class(item: TokenStream) -> TokenStream {
     derive_java_class::main(item)
 }
 
+// Rust error thrown as Java exception class
+#[proc_macro_derive(JavaException, attributes(java))]
+pub fn java_bindgen_java_exception(item: TokenStream) -> TokenStream {
+    derive_java_exception::main(item)
+}
+
 // Deliver Java Logger
 #[proc_macro_derive(JLogger)]
 pub fn java_bindgen_jlogger(item: TokenStream) -> TokenStream {
     derive_jlogger::main(item)
 }
 
+// Rust proxy for Java object implementing the trait methods
+#[proc_macro_attribute]
+pub fn java_interface(attr: TokenStream, item: TokenStream) -> TokenStream {
+    derive_java_interface::main(attr, item)
+}
+
 // Rust JVM Test
 #[proc_macro_attribute]
 pub fn test_jvm(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -1,7 +1,29 @@
 use crate::util::{ts2, CompileErrors};
+use java_bindgen_core::cargo_parser::IntegerPolicy;
 use quote::quote;
 use syn::__private::TokenStream2;
 
+// (Rust Type, Same Width, Widen) Java primitive or BigInteger
+const INTEGER_TYPES: &[(&str, &str, &str)] = &[
+    ("u8", "byte", "short"),
+    ("u16", "char", "int"),
+    ("u32", "int", "long"),
+    ("u64", "long", "BigInteger"),
+    ("usize", "long", "BigInteger"),
+    ("i128", "BigInteger", "BigInteger"),
+    ("u128", "BigInteger", "BigInteger"),
+];
+
+// Java type of unsigned / wide integers for the configured IntegerPolicy
+fn find_integer_type(rust_type: &str, policy: IntegerPolicy) -> Option<&'static str> {
+    let (_, same_width, widen) = INTEGER_TYPES.iter().find(|(rust, _, _)| *rust == rust_type)?;
+    Some(match policy {
+        IntegerPolicy::SameWidth => same_width,
+        IntegerPolicy::Widen => widen,
+        IntegerPolicy::BigInteger => "BigInteger",
+    })
+}
+
 // returns (path, type)
 fn extract_rust_type(ty: &TokenStream2) -> (String, String) {
     let ty = ty.to_string().replace("& mut", "").replace(' ', "");
@@ -19,50 +41,167 @@ fn extract_rust_type(ty: &TokenStream2) -> (String, String) {
     (left.to_string(), right.to_string())
 }
 
-// Extract T from JList<T>
-pub fn to_java_list(rust_type: String, errors: &mut CompileErrors) -> String {
-    let default = "List<Object>".to_string();
-    let Some(split_index) = rust_type.find('<') else {
-        return default;
-    };
-    let (_, right) = rust_type.split_at(split_index + 1);
-
-    let Some(split_index) = right.rfind('>') else {
-        return default;
-    };
-    let (ty, _) = right.split_at(split_index);
-
-    if ty == "u8" || ty == "i8" {
-        return "List<Byte>".to_string();
+// Java boxed type for generic arguments (List<E>, Map<K, V>)
+fn to_java_boxed(ty: &str, policy: IntegerPolicy, errors: &mut CompileErrors) -> String {
+    if let Some(java_type) = find_integer_type(ty, policy) {
+        return match java_type {
+            "byte" => "Byte",
+            "char" => "Character",
+            "short" => "Short",
+            "int" => "Integer",
+            "long" => "Long",
+            _ => java_type,
+        }
+        .to_string();
+    }
+    if ty == "i8" {
+        return "Byte".to_string();
     }
     if ty == "i16" {
-        return "List<Short>".to_string();
+        return "Short".to_string();
     }
     if ty == "i32" {
-        return "List<Integer>".to_string();
+        return "Integer".to_string();
     }
     if ty == "i64" {
-        return "List<Long>".to_string();
+        return "Long".to_string();
     }
     if ty == "f32" {
-        return "List<Float>".to_string();
+        return "Float".to_string();
     }
     if ty == "f64" {
-        return "List<Double>".to_string();
+        return "Double".to_string();
     }
     if ty == "char" {
-        return "List<Character>".to_string();
+        return "Character".to_string();
     }
     if ty == "bool" {
-        return "List<Boolean>".to_string();
+        return "Boolean".to_string();
     }
 
-    let obj = rewrite_rust_to_java(&ts2(ty), errors).unwrap_or("Object".to_string());
+    rewrite_rust_to_java(&ts2(ty), policy, errors).unwrap_or("Object".to_string())
+}
+
+// Extract T from JList<T>
+pub fn to_java_list(rust_type: String, policy: IntegerPolicy, errors: &mut CompileErrors) -> String {
+    let default = "List<Object>".to_string();
+    let Some(split_index) = rust_type.find('<') else {
+        return default;
+    };
+    let (_, right) = rust_type.split_at(split_index + 1);
+
+    let Some(split_index) = right.rfind('>') else {
+        return default;
+    };
+    let (ty, _) = right.split_at(split_index);
+
+    let obj = to_java_boxed(ty, policy, errors);
     format!("List<{obj}>")
 }
 
+// Extract T from JSet<T> / HashSet<T> / BTreeSet<T>
+fn to_java_set(rust_type: String, policy: IntegerPolicy, errors: &mut CompileErrors) -> String {
+    let default = "Set<Object>".to_string();
+    let Some(split_index) = rust_type.find('<') else {
+        return default;
+    };
+    let (_, right) = rust_type.split_at(split_index + 1);
+
+    let Some(split_index) = right.rfind('>') else {
+        return default;
+    };
+    let (ty, _) = right.split_at(split_index);
+
+    let obj = to_java_boxed(ty, policy, errors);
+    format!("Set<{obj}>")
+}
+
+// Extract K, V from JMap<K, V> / HashMap<K, V> / BTreeMap<K, V>
+fn to_java_map(rust_type: String, policy: IntegerPolicy, errors: &mut CompileErrors) -> String {
+    let default = "Map<Object, Object>".to_string();
+    let Some(split_index) = rust_type.find('<') else {
+        return default;
+    };
+    let (_, right) = rust_type.split_at(split_index + 1);
+
+    let Some(split_index) = right.rfind('>') else {
+        return default;
+    };
+    let (args, _) = right.split_at(split_index);
+
+    let [key, value] = split_generic_args(args)[..] else {
+        return default;
+    };
+
+    let key = to_java_boxed(key, policy, errors);
+    let value = to_java_boxed(value, policy, errors);
+    format!("Map<{key}, {value}>")
+}
+
+// Split at top level commas (K, V / (A, B), R)
+fn split_generic_args(args: &str) -> Vec<&str> {
+    let mut result = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        match c {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                result.push(&args[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if start < args.len() {
+        result.push(&args[start..]);
+    }
+    result
+}
+
+// JCallback<(A, B), R> to Java functional interface (Runnable, Supplier, Consumer, Function, BiConsumer, BiFunction)
+fn to_java_callback(rust_type: String, policy: IntegerPolicy, errors: &mut CompileErrors) -> String {
+    let default = "Runnable".to_string();
+    let Some(split_index) = rust_type.find('<') else {
+        return default;
+    };
+    let (_, right) = rust_type.split_at(split_index + 1);
+
+    let Some(split_index) = right.rfind('>') else {
+        return default;
+    };
+    let (generics, _) = right.split_at(split_index);
+    let generics = split_generic_args(generics);
+
+    let args = generics.first().copied().unwrap_or("()");
+    let args = args.strip_prefix('(').and_then(|a| a.strip_suffix(')')).unwrap_or(args);
+    let mut types: Vec<String> = split_generic_args(args)
+        .into_iter()
+        .map(|ty| to_java_boxed(ty, policy, errors))
+        .collect();
+
+    let ret = generics.get(1).copied().unwrap_or("()");
+    let interface = match (types.len(), ret == "()") {
+        (0, true) => "Runnable",
+        (0, false) => "Supplier",
+        (1, true) => "Consumer",
+        (1, false) => "Function",
+        (2, true) => "BiConsumer",
+        _ => "BiFunction",
+    };
+    if ret != "()" {
+        types.push(to_java_boxed(ret, policy, errors));
+    }
+
+    if types.is_empty() {
+        return interface.to_string();
+    }
+    format!("{interface}<{}>", types.join(", "))
+}
+
 // Extract T from Option<T>
-fn extract_from_option(rust_type: String, errors: &mut CompileErrors) -> String {
+fn extract_from_option(rust_type: String, policy: IntegerPolicy, errors: &mut CompileErrors) -> String {
     let default = "void".to_string();
     let Some(split_index) = rust_type.find('<') else {
         return default;
@@ -73,12 +212,128 @@ fn extract_from_option(rust_type: String, errors: &mut CompileErrors) -> String
         return default;
     };
     let (ty, _) = right.split_at(split_index);
-    rewrite_rust_to_java(&ts2(ty), errors).unwrap_or("void".to_string())
+    rewrite_rust_to_java(&ts2(ty), policy, errors).unwrap_or("void".to_string())
+}
+
+// Extract T from Vec<T> (Java T[])
+fn to_java_array(rust_type: String, policy: IntegerPolicy, errors: &mut CompileErrors) -> String {
+    let default = "Object[]".to_string();
+    let Some(split_index) = rust_type.find('<') else {
+        return default;
+    };
+    let (_, right) = rust_type.split_at(split_index + 1);
+
+    let Some(split_index) = right.rfind('>') else {
+        return default;
+    };
+    let (ty, _) = right.split_at(split_index);
+    let obj = rewrite_rust_to_java(&ts2(ty), policy, errors).unwrap_or("Object".to_string());
+    format!("{obj}[]")
+}
+
+// (Rust Type, Java Type, JNI Array)
+const PRIMITIVE_ARRAYS: &[(&str, &str, &str)] = &[
+    ("Vec<i16>", "short[]", "JShortArray"),
+    ("Vec<u16>", "char[]", "JCharArray"),
+    ("Vec<char>", "char[]", "JCharArray"),
+    ("Vec<i32>", "int[]", "JIntArray"),
+    ("Vec<i64>", "long[]", "JLongArray"),
+    ("Vec<f32>", "float[]", "JFloatArray"),
+    ("Vec<f64>", "double[]", "JDoubleArray"),
+    ("Vec<bool>", "boolean[]", "JBooleanArray"),
+];
+
+fn find_primitive_array(rust_type: &str) -> Option<&'static (&'static str, &'static str, &'static str)> {
+    PRIMITIVE_ARRAYS.iter().find(|(rust, _, jni)| {
+        *rust == rust_type || rust_type.starts_with(&format!("{jni}<"))
+    })
+}
+
+// Vec<u32>, Vec<u64>, ... follow the IntegerPolicy of the element (Java Type, JNI Array)
+fn find_integer_array(rust_type: &str, policy: IntegerPolicy) -> Option<(&'static str, &'static str)> {
+    let element = rust_type.strip_prefix("Vec<")?.strip_suffix('>')?;
+    match find_integer_type(element, policy)? {
+        "int" => Some(("int[]", "JIntArray")),
+        "long" => Some(("long[]", "JLongArray")),
+        "BigInteger" => Some(("BigInteger[]", "JObjectArray")),
+        _ => None,
+    }
+}
+
+// (Rust element, Java Type, JNI Array) borrowed &[T] / &mut [T]
+const PRIMITIVE_SLICES: &[(&str, &str, &str)] = &[
+    ("i8", "byte[]", "JByteArray"),
+    ("u8", "byte[]", "JByteArray"),
+    ("i16", "short[]", "JShortArray"),
+    ("u16", "char[]", "JCharArray"),
+    ("i32", "int[]", "JIntArray"),
+    ("i64", "long[]", "JLongArray"),
+    ("f32", "float[]", "JFloatArray"),
+    ("f64", "double[]", "JDoubleArray"),
+];
+
+// &[T], &mut [T], &'a [T] (no spaces)
+pub fn find_primitive_slice(rust_type: &str) -> Option<&'static (&'static str, &'static str, &'static str)> {
+    if !rust_type.starts_with('&') && !rust_type.starts_with('[') {
+        return None;
+    }
+    let slice = &rust_type[rust_type.find('[')?..];
+    let element = slice.strip_prefix('[')?.strip_suffix(']')?;
+    PRIMITIVE_SLICES.iter().find(|(rust, _, _)| *rust == element)
+}
+
+// (Rust Type, Java Type) java.time, java.util, java.math, java.nio.file
+const JAVA_LIBRARY_TYPES: &[(&str, &str)] = &[
+    ("Duration", "Duration"),
+    ("PathBuf", "Path"),
+    // chrono
+    ("DateTime<Utc>", "Instant"),
+    ("DateTime<FixedOffset>", "OffsetDateTime"),
+    ("NaiveDate", "LocalDate"),
+    ("NaiveDateTime", "LocalDateTime"),
+    // time
+    ("OffsetDateTime", "OffsetDateTime"),
+    ("PrimitiveDateTime", "LocalDateTime"),
+    // uuid
+    ("Uuid", "UUID"),
+    // rust_decimal
+    ("Decimal", "BigDecimal"),
+];
+
+// Remove paths (chrono::DateTime<chrono::Utc> -> DateTime<Utc>)
+fn strip_paths(rust_type: &str) -> String {
+    let mut stripped = String::new();
+    let mut segment = String::new();
+    for c in rust_type.chars() {
+        if c.is_alphanumeric() || c == '_' {
+            segment.push(c);
+        } else if c == ':' {
+            segment.clear();
+        } else {
+            stripped.push_str(&segment);
+            segment.clear();
+            stripped.push(c);
+        }
+    }
+    stripped.push_str(&segment);
+    stripped
+}
+
+fn find_java_library_type(path: &str, rust_type: &str) -> Option<&'static str> {
+    // time::Date
+    if rust_type == "Date" && path.ends_with("time::") {
+        return Some("LocalDate");
+    }
+    let rust_type = strip_paths(rust_type);
+    JAVA_LIBRARY_TYPES
+        .iter()
+        .find(|(rust, _)| *rust == rust_type)
+        .map(|(_, java)| *java)
 }
 
 // rewrite [Rust] to [Java Type]
-pub fn rewrite_rust_to_java(ty: &TokenStream2, errors: &mut CompileErrors) -> Option<String> {
-    let (_, rust_type) = extract_rust_type(&ty);
+pub fn rewrite_rust_to_java(ty: &TokenStream2, policy: IntegerPolicy, errors: &mut CompileErrors) -> Option<String> {
+    let (path, rust_type) = extract_rust_type(&ty);
 
     // ignored types
     if rust_type.starts_with("JNIEnv<") {
@@ -89,11 +344,19 @@ pub fn rewrite_rust_to_java(ty: &TokenStream2, errors: &mut CompileErrors) -> Op
     };
 
     if rust_type.starts_with("JList<") {
-        return Some(to_java_list(rust_type, errors));
+        return Some(to_java_list(rust_type, policy, errors));
+    };
+
+    if rust_type.starts_with("JSet<") || rust_type.starts_with("HashSet<") || rust_type.starts_with("BTreeSet<") {
+        return Some(to_java_set(rust_type, policy, errors));
+    };
+
+    if rust_type.starts_with("JMap<") || rust_type.starts_with("HashMap<") || rust_type.starts_with("BTreeMap<") {
+        return Some(to_java_map(rust_type, policy, errors));
     };
 
     if rust_type.starts_with("Option<") {
-        return Some(extract_from_option(rust_type, errors));
+        return Some(extract_from_option(rust_type, policy, errors));
     };
 
     // void
@@ -163,7 +426,10 @@ pub fn rewrite_rust_to_java(ty: &TokenStream2, errors: &mut CompileErrors) -> Op
     };
 
     // rust primitives
-    if rust_type == "u8" || rust_type == "i8" {
+    if let Some(java_type) = find_integer_type(&rust_type, policy) {
+        return Some(java_type.to_string());
+    }
+    if rust_type == "i8" {
         return Some("byte".to_string());
     }
     if rust_type == "i16" {
@@ -190,13 +456,37 @@ pub fn rewrite_rust_to_java(ty: &TokenStream2, errors: &mut CompileErrors) -> Op
     if rust_type == "Vec<u8>" {
         return Some("byte[]".to_string());
     }
+    if let Some((_, java_type, _)) = find_primitive_array(&rust_type) {
+        return Some(java_type.to_string());
+    }
+    if let Some((java_type, _)) = find_integer_array(&rust_type, policy) {
+        return Some(java_type.to_string());
+    }
+    if rust_type.starts_with("Vec<") {
+        return Some(to_java_array(rust_type, policy, errors));
+    }
+    if rust_type.starts_with("JObjectArray<") {
+        return Some("Object[]".to_string());
+    }
 
     // objects
 
+    if let Some(java_type) = find_java_library_type(&path, &rust_type) {
+        return Some(java_type.to_string());
+    }
+
     if rust_type == "String" {
         return Some("String".to_string());
     };
 
+    if rust_type == "JCallback" || rust_type.starts_with("JCallback<") {
+        return Some(to_java_callback(rust_type, policy, errors));
+    };
+
+    if rust_type == "JOwnedBuffer" || rust_type.starts_with("JDirectBuffer") {
+        return Some("ByteBuffer".to_string());
+    };
+
     if rust_type == "Vec<u8>" {
         return Some("byte[]".to_string());
     };
@@ -215,6 +505,7 @@ pub fn rewrite_rust_to_java(ty: &TokenStream2, errors: &mut CompileErrors) -> Op
 fn extract_jni_from_option(
     rust_type: String,
     lifetime: &TokenStream2,
+    policy: IntegerPolicy,
     errors: &mut CompileErrors,
 ) -> Option<TokenStream2> {
     let Some(split_index) = rust_type.find('<') else {
@@ -225,7 +516,7 @@ fn extract_jni_from_option(
         return None;
     };
     let (ty, _) = right.split_at(split_index);
-    rewrite_rust_type_to_jni(&ts2(ty), lifetime, errors)
+    rewrite_rust_type_to_jni(&ts2(ty), lifetime, policy, errors)
 }
 
 const OBJECT_TYPES: &[&str] = &[
@@ -236,6 +527,7 @@ const OBJECT_TYPES: &[&str] = &[
 pub fn rewrite_rust_type_to_jni(
     ty: &TokenStream2,
     lifetime: &TokenStream2,
+    policy: IntegerPolicy,
     errors: &mut CompileErrors,
 ) -> Option<TokenStream2> {
     let (_, rust_type) = extract_rust_type(&ty);
@@ -250,7 +542,13 @@ pub fn rewrite_rust_type_to_jni(
 
     // Option<T>
     if rust_type.starts_with("Option<") {
-        return extract_jni_from_option(rust_type, lifetime, errors);
+        return extract_jni_from_option(rust_type, lifetime, policy, errors);
+    };
+
+    // Borrowed primitive arrays
+    if let Some((_, _, jni_array)) = find_primitive_slice(&rust_type) {
+        let jni_array = quote::format_ident!("{jni_array}");
+        return Some(quote! { jni::objects::#jni_array #lifetime });
     };
 
     // JNI Types
@@ -266,8 +564,20 @@ pub fn rewrite_rust_type_to_jni(
         return Some(quote! { jni::objects::JObject #lifetime });
     };
 
+    // Unsigned / wide integers
+    if let Some(java_type) = find_integer_type(&rust_type, policy) {
+        return Some(match java_type {
+            "byte" => quote! { jni::sys::jbyte },
+            "char" => quote! { jni::sys::jchar },
+            "short" => quote! { jni::sys::jshort },
+            "int" => quote! { jni::sys::jint },
+            "long" => quote! { jni::sys::jlong },
+            _ => quote! { jni::objects::JObject #lifetime },
+        });
+    };
+
     // Primitives
-    if rust_type == "jbyte" || rust_type == "u8" || rust_type == "i8" {
+    if rust_type == "jbyte" || rust_type == "i8" {
         return Some(quote! { jni::sys::jbyte });
     };
     if rust_type == "jboolean" || rust_type == "bool" {
@@ -299,6 +609,17 @@ pub fn rewrite_rust_type_to_jni(
     if rust_type == "Vec<u8>" {
         return Some(quote! { jni::objects::JByteArray #lifetime  });
     };
+    if let Some((_, _, jni_array)) = find_primitive_array(&rust_type) {
+        let jni_array = quote::format_ident!("{jni_array}");
+        return Some(quote! { jni::objects::#jni_array #lifetime });
+    };
+    if let Some((_, jni_array)) = find_integer_array(&rust_type, policy) {
+        let jni_array = quote::format_ident!("{jni_array}");
+        return Some(quote! { jni::objects::#jni_array #lifetime });
+    };
+    if rust_type.starts_with("Vec<") || rust_type.starts_with("JObjectArray<") {
+        return Some(quote! { jni::objects::JObjectArray #lifetime });
+    };
 
     Some(quote! { jni::objects::JObject #lifetime })
 }
@@ -306,6 +627,7 @@ pub fn rewrite_rust_type_to_jni(
 #[cfg(test)]
 pub mod tests {
     use super::{extract_rust_type, rewrite_rust_to_java};
+    use java_bindgen_core::cargo_parser::IntegerPolicy;
     use crate::{
         types_conversion::rewrite_rust_type_to_jni,
         util::{ts2, CompileErrors},
@@ -329,39 +651,215 @@ pub mod tests {
     #[test]
     fn should_rewrite_to_java() {
         let errors = &mut CompileErrors::default();
-        let ty = rewrite_rust_to_java(&ts2("Option<String>"), errors);
+        let policy = IntegerPolicy::default();
+        let ty = rewrite_rust_to_java(&ts2("Option<String>"), policy, errors);
         assert_eq!(Some("String".to_string()), ty);
 
-        let ty = rewrite_rust_to_java(&ts2("Option<std::string::String>"), errors);
+        let ty = rewrite_rust_to_java(&ts2("Option<std::string::String>"), policy, errors);
         assert_eq!(Some("String".to_string()), ty);
 
-        let ty = rewrite_rust_to_java(&ts2("JList<std::string::String>"), errors);
+        let ty = rewrite_rust_to_java(&ts2("JList<std::string::String>"), policy, errors);
         assert_eq!(Some("List<String>".to_string()), ty);
 
-        let ty = rewrite_rust_to_java(&ts2("Option<JList<std::string::String>>"), errors);
+        let ty = rewrite_rust_to_java(&ts2("Option<JList<std::string::String>>"), policy, errors);
         assert_eq!(Some("List<String>".to_string()), ty);
 
-        let ty = rewrite_rust_to_java(&ts2("jni::sys::jint"), errors);
+        let ty = rewrite_rust_to_java(&ts2("jni::sys::jint"), policy, errors);
         assert_eq!(Some("int".to_string()), ty);
 
-        let ty = rewrite_rust_to_java(&ts2("java_bindgen::interop::JLong"), errors);
+        let ty = rewrite_rust_to_java(&ts2("java_bindgen::interop::JLong"), policy, errors);
         assert_eq!(Some("Long".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("Vec<f64>"), policy, errors);
+        assert_eq!(Some("double[]".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("JIntArray<'local>"), policy, errors);
+        assert_eq!(Some("int[]".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("Vec<String>"), policy, errors);
+        assert_eq!(Some("String[]".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("Vec<Vec<UserClass>>"), policy, errors);
+        assert_eq!(Some("UserClass[][]".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("HashMap<String, i32>"), policy, errors);
+        assert_eq!(Some("Map<String, Integer>".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("BTreeMap<JMap<String, bool>, Vec<i64>>"), policy, errors);
+        assert_eq!(Some("Map<Map<String, Boolean>, long[]>".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("BTreeSet<i64>"), policy, errors);
+        assert_eq!(Some("Set<Long>".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("JSet<HashSet<String>>"), policy, errors);
+        assert_eq!(Some("Set<Set<String>>".to_string()), ty);
+    }
+
+    #[test]
+    fn should_rewrite_java_time() {
+        let errors = &mut CompileErrors::default();
+        let policy = IntegerPolicy::default();
+        let ty = rewrite_rust_to_java(&ts2("std::time::Duration"), policy, errors);
+        assert_eq!(Some("Duration".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("chrono::DateTime<chrono::Utc>"), policy, errors);
+        assert_eq!(Some("Instant".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("DateTime<FixedOffset>"), policy, errors);
+        assert_eq!(Some("OffsetDateTime".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("Option<NaiveDateTime>"), policy, errors);
+        assert_eq!(Some("LocalDateTime".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("time::Date"), policy, errors);
+        assert_eq!(Some("LocalDate".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("Date"), policy, errors);
+        assert_eq!(Some("Date".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("JList<NaiveDate>"), policy, errors);
+        assert_eq!(Some("List<LocalDate>".to_string()), ty);
+    }
+
+    #[test]
+    fn should_rewrite_java_library_types() {
+        let errors = &mut CompileErrors::default();
+        let policy = IntegerPolicy::default();
+        let ty = rewrite_rust_to_java(&ts2("uuid::Uuid"), policy, errors);
+        assert_eq!(Some("UUID".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("rust_decimal::Decimal"), policy, errors);
+        assert_eq!(Some("BigDecimal".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("std::path::PathBuf"), policy, errors);
+        assert_eq!(Some("Path".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("JMap<Uuid, Decimal>"), policy, errors);
+        assert_eq!(Some("Map<UUID, BigDecimal>".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("JDirectBuffer<'a>"), policy, errors);
+        assert_eq!(Some("ByteBuffer".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("java_bindgen::buffer::JOwnedBuffer"), policy, errors);
+        assert_eq!(Some("ByteBuffer".to_string()), ty);
+    }
+
+    #[test]
+    fn should_rewrite_callbacks() {
+        let errors = &mut CompileErrors::default();
+        let policy = IntegerPolicy::default();
+        let ty = rewrite_rust_to_java(&ts2("JCallback<()>"), policy, errors);
+        assert_eq!(Some("Runnable".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("JCallback<(), String>"), policy, errors);
+        assert_eq!(Some("Supplier<String>".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("JCallback<(i32,)>"), policy, errors);
+        assert_eq!(Some("Consumer<Integer>".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("java_bindgen::callback::JCallback<(String,), i64>"), policy, errors);
+        assert_eq!(Some("Function<String, Long>".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("JCallback<(f64, bool)>"), policy, errors);
+        assert_eq!(Some("BiConsumer<Double, Boolean>".to_string()), ty);
+
+        let ty = rewrite_rust_to_java(&ts2("JCallback<(JList<i32>, String), JMap<String, i32>>"), policy, errors);
+        assert_eq!(Some("BiFunction<List<Integer>, String, Map<String, Integer>>".to_string()), ty);
+    }
+
+    #[test]
+    fn should_rewrite_primitive_slices() {
+        let errors = &mut CompileErrors::default();
+        let policy = IntegerPolicy::default();
+        let lifetime = ts2("<'local>");
+        let ty = rewrite_rust_type_to_jni(&ts2("&[i32]"), &lifetime, policy, errors).map(|ts| ts.to_string());
+        assert_eq!(Some("jni :: objects :: JIntArray <'local >"), ty.as_deref());
+
+        let ty = rewrite_rust_type_to_jni(&ts2("&mut [u8]"), &lifetime, policy, errors).map(|ts| ts.to_string());
+        assert_eq!(Some("jni :: objects :: JByteArray <'local >"), ty.as_deref());
+
+        assert_eq!(Some("double[]"), super::find_primitive_slice("&'a[f64]").map(|s| s.1));
+        assert_eq!(None, super::find_primitive_slice("&[bool]"));
+        assert_eq!(None, super::find_primitive_slice("Vec<i32>"));
+    }
+
+    #[test]
+    fn should_rewrite_integers_by_policy() {
+        let errors = &mut CompileErrors::default();
+        let lifetime = ts2("<'local>");
+
+        let policy = IntegerPolicy::SameWidth;
+        assert_eq!(Some("byte".to_string()), rewrite_rust_to_java(&ts2("u8"), policy, errors));
+        assert_eq!(Some("int".to_string()), rewrite_rust_to_java(&ts2("u32"), policy, errors));
+        assert_eq!(Some("List<Long>".to_string()), rewrite_rust_to_java(&ts2("JList<u64>"), policy, errors));
+        let ty = rewrite_rust_type_to_jni(&ts2("u32"), &lifetime, policy, errors).map(|ts| ts.to_string());
+        assert_eq!(Some("jni :: sys :: jint"), ty.as_deref());
+
+        let policy = IntegerPolicy::Widen;
+        assert_eq!(Some("short".to_string()), rewrite_rust_to_java(&ts2("u8"), policy, errors));
+        assert_eq!(Some("long".to_string()), rewrite_rust_to_java(&ts2("u32"), policy, errors));
+        assert_eq!(Some("BigInteger".to_string()), rewrite_rust_to_java(&ts2("u64"), policy, errors));
+        let ty = rewrite_rust_type_to_jni(&ts2("u32"), &lifetime, policy, errors).map(|ts| ts.to_string());
+        assert_eq!(Some("jni :: sys :: jlong"), ty.as_deref());
+        let ty = rewrite_rust_type_to_jni(&ts2("usize"), &lifetime, policy, errors).map(|ts| ts.to_string());
+        assert_eq!(Some("jni :: objects :: JObject <'local >"), ty.as_deref());
+
+        let policy = IntegerPolicy::BigInteger;
+        assert_eq!(Some("BigInteger".to_string()), rewrite_rust_to_java(&ts2("u16"), policy, errors));
+        assert_eq!(Some("Set<BigInteger>".to_string()), rewrite_rust_to_java(&ts2("HashSet<u8>"), policy, errors));
+        assert_eq!(Some("byte".to_string()), rewrite_rust_to_java(&ts2("i8"), policy, errors));
+    }
+
+    #[test]
+    fn should_rewrite_integer_arrays_by_policy() {
+        let errors = &mut CompileErrors::default();
+        let lifetime = ts2("<'local>");
+        let jni = |ty: &str, policy: IntegerPolicy, errors: &mut CompileErrors| {
+            rewrite_rust_type_to_jni(&ts2(ty), &lifetime, policy, errors).map(|ts| ts.to_string())
+        };
+
+        let policy = IntegerPolicy::SameWidth;
+        assert_eq!(Some("int[]".to_string()), rewrite_rust_to_java(&ts2("Vec<u32>"), policy, errors));
+        assert_eq!(Some("long[]".to_string()), rewrite_rust_to_java(&ts2("Vec<u64>"), policy, errors));
+        assert_eq!(Some("jni :: objects :: JIntArray <'local >"), jni("Vec<u32>", policy, errors).as_deref());
+        assert_eq!(Some("jni :: objects :: JLongArray <'local >"), jni("Vec<u64>", policy, errors).as_deref());
+
+        let policy = IntegerPolicy::Widen;
+        assert_eq!(Some("long[]".to_string()), rewrite_rust_to_java(&ts2("Vec<u32>"), policy, errors));
+        assert_eq!(Some("BigInteger[]".to_string()), rewrite_rust_to_java(&ts2("Vec<u64>"), policy, errors));
+        assert_eq!(Some("jni :: objects :: JLongArray <'local >"), jni("Vec<u32>", policy, errors).as_deref());
+        assert_eq!(Some("jni :: objects :: JObjectArray <'local >"), jni("Vec<u64>", policy, errors).as_deref());
+
+        let policy = IntegerPolicy::BigInteger;
+        assert_eq!(Some("BigInteger[]".to_string()), rewrite_rust_to_java(&ts2("Vec<u32>"), policy, errors));
+        assert_eq!(Some("BigInteger[]".to_string()), rewrite_rust_to_java(&ts2("Vec<u64>"), policy, errors));
+        assert_eq!(Some("jni :: objects :: JObjectArray <'local >"), jni("Vec<u32>", policy, errors).as_deref());
+        // byte[] and char[] don't depend on the policy
+        assert_eq!(Some("byte[]".to_string()), rewrite_rust_to_java(&ts2("Vec<u8>"), policy, errors));
+        assert_eq!(Some("char[]".to_string()), rewrite_rust_to_java(&ts2("Vec<u16>"), policy, errors));
     }
 
     #[test]
     fn should_rewrite_to_jni() {
         let errors = &mut CompileErrors::default();
+        let policy = IntegerPolicy::default();
         let lifetime = ts2("<'local>");
-        let ty = rewrite_rust_type_to_jni(&ts2("JNIEnv<'l>"), &lifetime, errors).map(|ts| ts.to_string());
+        let ty = rewrite_rust_type_to_jni(&ts2("JNIEnv<'l>"), &lifetime, policy, errors).map(|ts| ts.to_string());
         assert_eq!(None, ty);
 
-        let ty = rewrite_rust_type_to_jni(&ts2("&mut JNIEnv<'l>"), &lifetime, errors).map(|ts| ts.to_string());
+        let ty = rewrite_rust_type_to_jni(&ts2("&mut JNIEnv<'l>"), &lifetime, policy, errors).map(|ts| ts.to_string());
         assert_eq!(None, ty);
 
-        let ty = rewrite_rust_type_to_jni(&ts2("JDouble"), &lifetime, errors).map(|ts| ts.to_string());
+        let ty = rewrite_rust_type_to_jni(&ts2("JDouble"), &lifetime, policy, errors).map(|ts| ts.to_string());
         assert_eq!(Some("jni :: objects :: JObject <'local >"), ty.as_deref());       
         
-        let ty = rewrite_rust_type_to_jni(&ts2("MyCustomClassStruct"), &lifetime, errors).map(|ts| ts.to_string());
+        let ty = rewrite_rust_type_to_jni(&ts2("Vec<char>"), &lifetime, policy, errors).map(|ts| ts.to_string());
+        assert_eq!(Some("jni :: objects :: JCharArray <'local >"), ty.as_deref());
+
+        let ty = rewrite_rust_type_to_jni(&ts2("Vec<String>"), &lifetime, policy, errors).map(|ts| ts.to_string());
+        assert_eq!(Some("jni :: objects :: JObjectArray <'local >"), ty.as_deref());
+
+        let ty = rewrite_rust_type_to_jni(&ts2("MyCustomClassStruct"), &lifetime, policy, errors).map(|ts| ts.to_string());
         assert_eq!(Some("jni :: objects :: JObject <'local >"), ty.as_deref());
     }
 }
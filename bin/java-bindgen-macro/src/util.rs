@@ -6,8 +6,28 @@ use std::str::FromStr;
 use syn::__private::TokenStream2;
 
 pub fn parse_attr_to_map(attr: TokenStream) -> HashMap<String, String> {
+    parse_attr_str_to_map(&attr.to_string())
+}
+
+pub fn parse_attr_str_to_map(attr: &str) -> HashMap<String, String> {
+    // key = "value" entries (comma inside quotes is part of the value)
+    let mut entries = vec![];
+    let mut in_quotes = false;
+    let mut start = 0;
+    for (i, c) in attr.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => {
+                entries.push(&attr[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    entries.push(&attr[start..]);
+
     let mut map: HashMap<String, String> = HashMap::new();
-    for entry in attr.to_string().split(',') {
+    for entry in entries {
         let key_value = entry.split('=').collect::<Vec<&str>>();
         let key = key_value.first().unwrap_or(&"");
         let value = key_value.get(1).unwrap_or(&"");
@@ -110,3 +130,23 @@ pub fn lifetime_from_type(ty: &syn::Type) -> Option<TokenStream2> {
 
     None
 }
+
+// Replace 'Self' with concrete type (impl blocks)
+pub fn replace_self_type(tokens: &TokenStream2, self_ty: &str) -> TokenStream2 {
+    let tokens_str = format!(" {tokens} ");
+    if !tokens_str.contains(" Self ") {
+        return tokens.clone();
+    }
+    ts2(&tokens_str.replace(" Self ", &format!(" {self_ty} ")))
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn should_parse_quoted_commas() {
+        let map = super::parse_attr_str_to_map(r#"throws = "java.io.IOException, com.acme.ImageException", package = "com.acme""#);
+        assert_eq!(map["throws"], "java.io.IOException, com.acme.ImageException");
+        assert_eq!(map["package"], "com.acme");
+    }
+}
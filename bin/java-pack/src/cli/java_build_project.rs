@@ -1,4 +1,5 @@
 use std::{
+    collections::HashSet,
     fs::{self},
     path::{Path, PathBuf},
 };
@@ -6,7 +7,11 @@ use std::{
 use super::{cli_utils::{self, create_file}, java_templates::build::*};
 use color_eyre::eyre::Context;
 use java_bindgen_core::{
-    consts, ffi_store::FFIStore, project_info::ProjectInfo, utils::create_or_get_dir,
+    cargo_parser::ClassStyle,
+    consts,
+    ffi_store::{FFIStore, JavaFFIAccessor, JavaFFIClass, JavaFFIClassKind, JavaFFIMethod, JavaFFIVariant},
+    project_info::ProjectInfo,
+    utils::create_or_get_dir,
 };
 
 #[derive(Debug, Default)]
@@ -17,35 +22,537 @@ pub struct RustBinaryInfo {
 }
 
 pub struct JavaClass {
+    // Package directories (com/acme/model)
+    package_path: Vec<String>,
     file_name: String,
     class_import: String,
     file_content: String,
 }
 
+fn produce_java_methods(methods: &[JavaFFIMethod]) -> String {
+    methods
+        .iter()
+        .map(|m| format!("\t{};", m.sig))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// (Java Type, import)
+const JAVA_TYPE_IMPORTS: &[(&str, &str)] = &[
+    ("BigDecimal", "java.math.BigDecimal"),
+    ("BigInteger", "java.math.BigInteger"),
+    ("ByteBuffer", "java.nio.ByteBuffer"),
+    ("Path", "java.nio.file.Path"),
+    ("Duration", "java.time.Duration"),
+    ("Instant", "java.time.Instant"),
+    ("LocalDate", "java.time.LocalDate"),
+    ("LocalDateTime", "java.time.LocalDateTime"),
+    ("OffsetDateTime", "java.time.OffsetDateTime"),
+    ("UUID", "java.util.UUID"),
+    ("BiConsumer", "java.util.function.BiConsumer"),
+    ("BiFunction", "java.util.function.BiFunction"),
+    ("Consumer", "java.util.function.Consumer"),
+    ("Function", "java.util.function.Function"),
+    ("Supplier", "java.util.function.Supplier"),
+];
+
+// Import the Java library types used in the signatures (skip names taken by generated classes)
+fn produce_java_type_imports<'a>(sources: impl IntoIterator<Item = &'a str>, ffi: &FFIStore) -> String {
+    let used: HashSet<&str> = sources
+        .into_iter()
+        .flat_map(|s| s.split(|c: char| !(c.is_alphanumeric() || c == '_')))
+        .collect();
+    let classes = ffi.get_classes();
+    JAVA_TYPE_IMPORTS
+        .iter()
+        .filter(|(ty, _)| used.contains(ty) && !classes.iter().any(|c| c.java_name() == *ty))
+        .map(|(_, import)| format!("import {import};"))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// Import the generated classes from other packages used in the signatures
+fn produce_java_class_imports(sources: &[&str], ffi: &FFIStore, project_info: &ProjectInfo, package: &str) -> Vec<String> {
+    let used: HashSet<&str> = sources
+        .iter()
+        .flat_map(|s| s.split(|c: char| !(c.is_alphanumeric() || c == '_')))
+        .collect();
+    ffi.get_classes()
+        .iter()
+        .filter(|c| used.contains(c.java_name()))
+        .map(|c| (project_info.get_class_package_name(c.package.as_deref()), c.java_name()))
+        .filter(|(class_package, _)| class_package != package)
+        .map(|(class_package, name)| format!("import {class_package}.{name};"))
+        .collect()
+}
+
+// Rust type names -> Java class names in a type (#[java(name = "ImageRequest")])
+fn rename_java_types(text: &str, ffi: &FFIStore) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut word = String::new();
+    for c in text.chars().map(Some).chain([None]) {
+        match c {
+            Some(c) if c.is_alphanumeric() || c == '_' => word.push(c),
+            _ => {
+                if !word.is_empty() {
+                    result.push_str(&ffi.get_java_class_name(&word).unwrap_or(word.clone()));
+                    word.clear();
+                }
+                result.extend(c);
+            }
+        }
+    }
+    result
+}
+
+// Rename the return and parameter types of a method signature (not the method and parameter names)
+fn rename_java_sig_types(sig: &str, ffi: &FFIStore) -> String {
+    let (Some(open), Some(close)) = (sig.find('('), sig.rfind(')')) else {
+        return sig.to_string();
+    };
+    // "public static native List<Shape>" "scale"
+    let head = &sig[..open];
+    let name_start = head.rfind(' ').map(|i| i + 1).unwrap_or(0);
+
+    // "Shape shape,List<Shape> shapes" split on top level commas
+    let params = &sig[open + 1..close];
+    let mut args: Vec<String> = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in params.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(rename_java_arg_type(&params[start..i], ffi));
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if start < params.len() {
+        args.push(rename_java_arg_type(&params[start..], ffi));
+    }
+
+    format!(
+        "{}{}({}){}",
+        rename_java_types(&head[..name_start], ffi),
+        &head[name_start..],
+        args.join(","),
+        &sig[close + 1..]
+    )
+}
+
+// "Shape shape" -> "JavaShape shape"
+fn rename_java_arg_type(arg: &str, ffi: &FFIStore) -> String {
+    match arg.rsplit_once(' ') {
+        Some((ty, name)) => format!("{} {name}", rename_java_types(ty, ffi)),
+        None => arg.to_string(),
+    }
+}
+
+// Method signature with Java class names and throws clause
+fn java_method_sig(method: &JavaFFIMethod, ffi: &FFIStore, project_info: &ProjectInfo) -> String {
+    let sig = rename_java_sig_types(&method.sig, ffi);
+    let classes = ffi.get_classes();
+    let mut throws: Vec<String> = vec![];
+    for class in method.throws.iter() {
+        // Rust error path (errors::ImageError) or Java class name of #[derive(JavaException)]
+        let type_name = class.rsplit("::").next().unwrap_or(class);
+        let exception = classes.iter().find(|c| {
+            c.kind == JavaFFIClassKind::Exception
+                && !class.contains('.')
+                && (c.id == type_name || c.java_name() == class)
+        });
+        let java_class = match exception {
+            // fully qualified: the Lib class imports java.io.IOException
+            Some(exception) => format!(
+                "{}.{}",
+                project_info.get_class_package_name(exception.package.as_deref()),
+                exception.java_name()
+            ),
+            // fully qualified Java class (java.io.IOException)
+            None if class.contains('.') => class.clone(),
+            // Rust error without #[derive(JavaException)]
+            None => continue,
+        };
+        if !throws.contains(&java_class) {
+            throws.push(java_class);
+        }
+    }
+
+    match throws.is_empty() {
+        true => sig,
+        false => format!("{sig} throws {}", throws.join(", ")),
+    }
+}
+
+// FFI definitions with Java class names in the signatures
+pub fn with_java_names(ffi: &FFIStore, project_info: &ProjectInfo) -> FFIStore {
+    let rename = |text: &str| rename_java_types(text, ffi);
+    let rename_fields = |fields: Vec<(String, String)>| -> Vec<(String, String)> {
+        fields.into_iter().map(|(name, ty)| (name, rename(&ty))).collect()
+    };
+
+    let mut store = FFIStore::default();
+    for method in ffi.get_methods() {
+        store.add_ffi_method(JavaFFIMethod { sig: java_method_sig(&method, ffi, project_info), ..method });
+    }
+    for class in ffi.get_classes() {
+        store.add_ffi_class(JavaFFIClass {
+            fields: rename_fields(class.fields),
+            variants: class
+                .variants
+                .into_iter()
+                .map(|v| JavaFFIVariant { fields: rename_fields(v.fields), ..v })
+                .collect(),
+            methods: class
+                .methods
+                .into_iter()
+                .map(|m| JavaFFIMethod { sig: java_method_sig(&m, ffi, project_info), ..m })
+                .collect(),
+            ..class
+        });
+    }
+    store
+}
+
+// Java class of static method: (package, class name), None for lib class methods
+fn method_class(project_info: &ProjectInfo, method: &JavaFFIMethod) -> Option<(String, String)> {
+    let package = project_info.get_class_package_name(method.package.as_deref());
+    let class = method.class.clone().unwrap_or_else(|| project_info.get_java_class_name());
+    match package == project_info.java_package_name && class == project_info.get_java_class_name() {
+        true => None,
+        false => Some((package, class)),
+    }
+}
+
+fn lib_methods(project_info: &ProjectInfo, ffi: &FFIStore) -> Vec<JavaFFIMethod> {
+    ffi.get_methods()
+        .into_iter()
+        .filter(|m| method_class(project_info, m).is_none())
+        .collect()
+}
+
+// #[java_bindgen(class = "ImageOps")] methods grouped by class
+fn facade_classes(project_info: &ProjectInfo, ffi: &FFIStore) -> Vec<((String, String), Vec<JavaFFIMethod>)> {
+    let mut result: Vec<((String, String), Vec<JavaFFIMethod>)> = vec![];
+    for method in ffi.get_methods() {
+        let Some(key) = method_class(project_info, &method) else {
+            continue;
+        };
+        match result.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, methods)) => methods.push(method),
+            None => result.push((key, vec![method])),
+        }
+    }
+    result
+}
+
+fn produce_java_class(
+    template: &str,
+    project_info: &ProjectInfo,
+    ffi: &FFIStore,
+    package: &str,
+    name: &str,
+    sources: &[&str],
+    replace: impl Fn(String) -> String,
+) -> JavaClass {
+    let mut imports = vec![produce_java_type_imports(sources.iter().copied(), ffi)];
+    imports.retain(|i| !i.is_empty());
+    imports.extend(produce_java_class_imports(sources, ffi, project_info, package));
+    if package != project_info.java_package_name && template.contains("[[lib-class-name]]") {
+        imports.push(format!(
+            "import {}.{};",
+            project_info.java_package_name,
+            project_info.get_java_class_name()
+        ));
+    }
+
+    let file_content = template
+        .replace("[[package_name]]", package)
+        .replace("[[java-type-imports]]", &imports.join("\n"))
+        .replace("[[lib-class-name]]", &project_info.get_java_class_name())
+        .replace("[[java-class-name]]", name);
+
+    JavaClass {
+        package_path: project_info.get_class_packages_path(Some(package)),
+        file_name: format!("{name}.java"),
+        file_content: replace(file_content),
+        class_import: format!("import {package}.{name};"),
+    }
+}
+
 pub fn produce_java_classes(project_info: &ProjectInfo, ffi: &FFIStore) -> Vec<JavaClass> {
     let mut result = vec![];
+    let mut facades = facade_classes(project_info, ffi);
     for class in ffi.get_classes() {
-        let class_fields: Vec<String> = class
+        let package = project_info.get_class_package_name(class.package.as_deref());
+        let name = class.java_name();
+        let class_fields = produce_java_class_fields(project_info, &class);
+
+        let (template, class_variants) = match class.kind {
+            JavaFFIClassKind::Class => match class.accessor {
+                JavaFFIAccessor::Getter if project_info.class_style == ClassStyle::Lombok => {
+                    (JAVA_CLASS_TEMPLATE, String::new())
+                }
+                JavaFFIAccessor::Getter => (JAVA_POJO_TEMPLATE, String::new()),
+                JavaFFIAccessor::Record => (JAVA_RECORD_TEMPLATE, String::new()),
+                JavaFFIAccessor::Field => (JAVA_FIELDS_CLASS_TEMPLATE, String::new()),
+            },
+            JavaFFIClassKind::Handle => (JAVA_HANDLE_TEMPLATE, String::new()),
+            JavaFFIClassKind::Enum => (JAVA_ENUM_TEMPLATE, produce_java_enum_constants(&class.variants)),
+            JavaFFIClassKind::Sealed => (JAVA_SEALED_TEMPLATE, produce_java_records(name, &class.variants)),
+            JavaFFIClassKind::Exception => (JAVA_EXCEPTION_TEMPLATE, produce_java_exceptions(name, &class.variants)),
+        };
+
+        // Static methods of the same class are declared by the handle
+        let mut methods = class.methods.clone();
+        if class.kind == JavaFFIClassKind::Handle {
+            let key = (package.clone(), name.to_string());
+            if let Some(index) = facades.iter().position(|(k, _)| *k == key) {
+                methods.extend(facades.remove(index).1);
+            }
+        }
+
+        let sources: Vec<&str> = class
             .fields
             .iter()
-            .map(|f| format!("\t{} {};", f.1, f.0))
+            .chain(class.variants.iter().flat_map(|v| v.fields.iter()))
+            .map(|f| f.1.as_str())
+            .chain(methods.iter().map(|m| m.sig.as_str()))
             .collect();
 
-        let file_content = JAVA_CLASS_TEMPLATE
-            .replace("[[package_name]]", &project_info.java_package_name)
-            .replace("[[java-class-name]]", &class.id)
-            .replace("[[java-class-fields]]", &class_fields.join("\n"));
+        result.push(produce_java_class(template, project_info, ffi, &package, name, &sources, |content| {
+            content
+                .replace("[[java-class-fields]]", &class_fields)
+                .replace("[[java-class-accessors]]", &produce_java_class_accessors(&class))
+                .replace("[[java-pojo-methods]]", &produce_java_pojo_methods(&class))
+                .replace("[[java-class-variants]]", &class_variants)
+                .replace(
+                    "[[java-exception-extends]]",
+                    class.extends.as_deref().unwrap_or("java.lang.RuntimeException"),
+                )
+                .replace("[[java-bind-methods]]", &produce_java_methods(&methods))
+        }));
+    }
 
-        result.push(JavaClass {
-            file_name: format!("{}.java", &class.id),
-            file_content,
-            class_import: format!("import {}.{};", &project_info.java_package_name, &class.id),
-        })
+    for ((package, name), methods) in facades {
+        let sources: Vec<&str> = methods.iter().map(|m| m.sig.as_str()).collect();
+        result.push(produce_java_class(JAVA_FACADE_TEMPLATE, project_info, ffi, &package, &name, &sources, |content| {
+            content.replace("[[java-bind-methods]]", &produce_java_methods(&methods))
+        }));
     }
 
     result
 }
 
+// Getter name generated by Lombok: getX() / isX()
+fn java_getter_name(field: &str, ty: &str) -> String {
+    let prefix = if ty == "boolean" || ty == "Boolean" { "is" } else { "get" };
+    // Lombok keeps boolean `isX` fields as they are: isDone -> isDone()
+    if prefix == "is" && field.strip_prefix("is").and_then(|f| f.chars().next()).is_some_and(char::is_uppercase) {
+        return field.to_string();
+    }
+    match field.chars().next() {
+        Some(c) => format!("{prefix}{}{}", c.to_uppercase(), &field[c.len_utf8()..]),
+        None => prefix.to_string(),
+    }
+}
+
+// Java field name (public field may use own name)
+fn java_field_name<'a>(class: &'a JavaFFIClass, name: &'a str) -> &'a str {
+    match class.field_accessors.iter().find(|a| a.0 == name) {
+        Some((_, JavaFFIAccessor::Field, java_name)) => java_name,
+        _ => name,
+    }
+}
+
+// Record components or class fields (public when read as field)
+fn produce_java_class_fields(project_info: &ProjectInfo, class: &JavaFFIClass) -> String {
+    if class.accessor == JavaFFIAccessor::Record {
+        let components: Vec<String> = class.fields.iter().map(|f| format!("{} {}", f.1, f.0)).collect();
+        return components.join(", ");
+    }
+
+    let is_pojo = project_info.class_style != ClassStyle::Lombok;
+    let fields: Vec<String> = class
+        .fields
+        .iter()
+        .map(|(name, ty)| {
+            let field_accessor = class.field_accessors.iter().find(|a| &a.0 == name);
+            match field_accessor {
+                Some((_, JavaFFIAccessor::Field, java_name)) => format!("\tpublic {ty} {java_name};"),
+                _ if class.accessor == JavaFFIAccessor::Field => format!("\tpublic {ty} {name};"),
+                _ if is_pojo => format!("\tprivate {ty} {name};"),
+                _ => format!("\t{ty} {name};"),
+            }
+        })
+        .collect();
+    fields.join("\n")
+}
+
+// Constructor, getters, equals, hashCode and toString (same as Lombok @Data @AllArgsConstructor)
+fn produce_java_pojo_methods(class: &JavaFFIClass) -> String {
+    let name = class.java_name();
+    let fields: Vec<(&str, &str, bool)> = class
+        .fields
+        .iter()
+        .map(|(field, ty)| (java_field_name(class, field), ty.as_str(), ty.ends_with("[]")))
+        .collect();
+
+    let args: Vec<String> = class.fields.iter().map(|(field, ty)| format!("{ty} {field}")).collect();
+    let assignments: String = class
+        .fields
+        .iter()
+        .zip(fields.iter())
+        .map(|((arg, _), (field, _, _))| format!("\t\tthis.{field} = {arg};\n"))
+        .collect();
+    let constructor = format!("\tpublic {name}({}) {{\n{assignments}\t}}", args.join(", "));
+
+    let getters = class.fields.iter().zip(fields.iter()).map(|((arg, ty), (field, _, _))| {
+        format!(
+            "\tpublic {ty} {}() {{\n\t\treturn {field};\n\t}}",
+            java_getter_name(arg, ty)
+        )
+    });
+
+    let mut compare: Vec<String> = fields
+        .iter()
+        .map(|(field, _, is_array)| match is_array {
+            true => format!("java.util.Arrays.equals({field}, other.{field})"),
+            false => format!("java.util.Objects.equals({field}, other.{field})"),
+        })
+        .collect();
+    if compare.is_empty() {
+        compare.push("true".to_string());
+    }
+    let equals = format!(
+        "\t@Override\n\tpublic boolean equals(Object o) {{\n\
+         \t\tif (this == o) return true;\n\
+         \t\tif (o == null || getClass() != o.getClass()) return false;\n\
+         \t\t{name} other = ({name}) o;\n\
+         \t\treturn {};\n\t}}",
+        compare.join(" && ")
+    );
+
+    let hashes: String = fields
+        .iter()
+        .map(|(field, _, is_array)| match is_array {
+            true => format!("\t\tresult = 31 * result + java.util.Arrays.hashCode({field});\n"),
+            false => format!("\t\tresult = 31 * result + java.util.Objects.hashCode({field});\n"),
+        })
+        .collect();
+    let hash_code = format!("\t@Override\n\tpublic int hashCode() {{\n\t\tint result = 1;\n{hashes}\t\treturn result;\n\t}}");
+
+    let values: Vec<String> = fields
+        .iter()
+        .map(|(field, _, is_array)| match is_array {
+            true => format!("{field}=\" + java.util.Arrays.toString({field}) + \""),
+            false => format!("{field}=\" + {field} + \""),
+        })
+        .collect();
+    let to_string = format!(
+        "\t@Override\n\tpublic String toString() {{\n\t\treturn \"{name}({})\";\n\t}}",
+        values.join(", ")
+    );
+
+    std::iter::once(constructor)
+        .chain(getters)
+        .chain([equals, hash_code, to_string])
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+// Lombok is needed only by the @Data classes
+fn uses_lombok(project_info: &ProjectInfo, ffi: &FFIStore) -> bool {
+    project_info.class_style == ClassStyle::Lombok
+        && ffi
+            .get_classes()
+            .iter()
+            .any(|c| c.kind == JavaFFIClassKind::Class && c.accessor == JavaFFIAccessor::Getter)
+}
+
+// Accessors with custom names (not generated by Lombok / record)
+fn produce_java_class_accessors(class: &JavaFFIClass) -> String {
+    let mut accessors = vec![];
+    for (name, accessor, java_name) in class.field_accessors.iter() {
+        let Some((_, ty)) = class.fields.iter().find(|f| &f.0 == name) else {
+            continue;
+        };
+        let generated = match (accessor, class.accessor) {
+            (JavaFFIAccessor::Field, _) => true,
+            (_, JavaFFIAccessor::Getter) => *java_name == java_getter_name(name, ty),
+            (_, JavaFFIAccessor::Record) => java_name == name,
+            (_, JavaFFIAccessor::Field) => false,
+        };
+        if !generated {
+            accessors.push(format!("\tpublic {ty} {java_name}() {{\n\t\treturn {name};\n\t}}"));
+        }
+    }
+    accessors.join("\n")
+}
+
+fn produce_java_enum_constants(variants: &[JavaFFIVariant]) -> String {
+    let constants: Vec<String> = variants.iter().map(|v| format!("\t{}", v.id)).collect();
+    constants.join(",\n")
+}
+
+fn produce_java_records(interface_name: &str, variants: &[JavaFFIVariant]) -> String {
+    let records: Vec<String> = variants
+        .iter()
+        .map(|v| {
+            let components: Vec<String> = v.fields.iter().map(|f| format!("{} {}", f.1, f.0)).collect();
+            format!(
+                "\trecord {}({}) implements {interface_name} {{}}",
+                v.id,
+                components.join(", ")
+            )
+        })
+        .collect();
+    records.join("\n")
+}
+
+// Enum variants as nested exception classes (catch (ImageError.Decode e))
+fn produce_java_exceptions(class_name: &str, variants: &[JavaFFIVariant]) -> String {
+    let exceptions: Vec<String> = variants
+        .iter()
+        .map(|v| {
+            format!(
+                "    public static class {0} extends {class_name} {{\n        public {0}(String message) {{\n            super(message);\n        }}\n\n        public {0}(String message, Throwable cause) {{\n            super(message, cause);\n        }}\n    }}",
+                v.id
+            )
+        })
+        .collect();
+    exceptions.join("\n\n")
+}
+
+// Direct ByteBuffers returned from Rust are freed by Lib.DirectBufferCleaner
+fn uses_byte_buffer(ffi: &FFIStore) -> bool {
+    let is_buffer = |ty: &str| ty.contains("ByteBuffer");
+    ffi.get_methods().iter().any(|m| is_buffer(&m.sig))
+        || ffi.get_classes().iter().any(|c| {
+            c.methods.iter().any(|m| is_buffer(&m.sig))
+                || c.fields.iter().any(|f| is_buffer(&f.1))
+                || c.variants.iter().flat_map(|v| v.fields.iter()).any(|f| is_buffer(&f.1))
+        })
+}
+
+// Sealed interfaces and records require Java 17, java.lang.ref.Cleaner Java 9
+fn java_version(ffi: &FFIStore) -> &'static str {
+    let has_records = ffi
+        .get_classes()
+        .iter()
+        .any(|c| c.kind == JavaFFIClassKind::Sealed || c.accessor == JavaFFIAccessor::Record);
+    if has_records {
+        "17"
+    } else if uses_byte_buffer(ffi) {
+        "9"
+    } else {
+        "1.8"
+    }
+}
+
 pub fn process_template(
     template: &str,
     project_info: &ProjectInfo,
@@ -60,22 +567,23 @@ pub fn process_template(
         .map(|class| class.class_import.clone())
         .collect();
     let class_imports = class_imports.join("\n");
+    let methods = lib_methods(project_info, ffi);
+    let type_imports = produce_java_type_imports(methods.iter().map(|m| m.sig.as_str()), ffi);
+    let buffer_cleaner = if uses_byte_buffer(ffi) { JAVA_BUFFER_CLEANER_TEMPLATE } else { "" };
+    let lombok = if uses_lombok(project_info, ffi) { JAVA_LOMBOK_DEPENDENCY_TEMPLATE } else { "" };
 
     template
         .replace("[[package_name]]", &project_info.java_package_name)
         .replace("[[class-imports]]", &class_imports)
+        .replace("[[java-type-imports]]", &type_imports)
         .replace("[[lib-name]]", &project_info.lib_name)
         .replace("[[lib-version]]", &project_info.lib_version)
         .replace("[[java-class-name]]", &project_info.get_java_class_name())
         .replace("[[lib-release-date]]", &release_date)
-        .replace(
-            "[[java-bind-methods]]",
-            &ffi.get_methods()
-                .into_iter()
-                .map(|m| format!("\t{};", m.sig))
-                .collect::<Vec<String>>()
-                .join("\n"),
-        )
+        .replace("[[java-version]]", java_version(ffi))
+        .replace("[[java-bind-methods]]", &produce_java_methods(&methods))
+        .replace("[[java-buffer-cleaner]]", buffer_cleaner)
+        .replace("[[java-lombok-dependency]]", lombok)
 }
 
 fn copy_binary(
@@ -103,7 +611,10 @@ pub fn setup_java_project(
 ) -> color_eyre::Result<()> {
     // Create directory
     let java_dir = create_or_get_dir(java_dir)?;
-    let ffi_store = FFIStore::open_read_only(&consts::ffi_definitions_path(project_dir));
+    let ffi_store = with_java_names(
+        &FFIStore::open_read_only(&consts::ffi_definitions_path(project_dir)),
+        project_info,
+    );
     let java_classes = produce_java_classes(project_info, &ffi_store);
 
     // Create pom
@@ -131,10 +642,22 @@ pub fn setup_java_project(
         &process_template(JAVA_LIB_TEMPLATE, project_info, &ffi_store, &java_classes),
     )?;
 
-    // Create classes
+    // Create RustPanicException.java (thrown by the native methods)
+    create_file(
+        &lib_java_class_directory,
+        "RustPanicException.java",
+        &process_template(JAVA_PANIC_EXCEPTION_TEMPLATE, project_info, &ffi_store, &java_classes),
+    )?;
+
+    // Create classes (in own package directory)
     for class in java_classes.into_iter() {
+        let mut class_directory = java_dir.clone();
+        for dir_name in class.package_path.iter() {
+            class_directory = class_directory.join(dir_name);
+            create_or_get_dir(&class_directory)?;
+        }
         create_file(
-            &lib_java_class_directory,
+            &class_directory,
             &class.file_name,
             &class.file_content,
         )?;
@@ -270,16 +793,27 @@ pub fn copy_jar_to(
 
 #[cfg(test)]
 pub mod test {
-    use java_bindgen_core::project_info::ProjectInfo;
+    use java_bindgen_core::{
+        cargo_parser::ClassStyle,
+        ffi_store::{FFIStore, JavaFFIAccessor, JavaFFIClass, JavaFFIClassKind, JavaFFIMethod, JavaFFIVariant},
+        project_info::ProjectInfo,
+    };
     use std::path::Path;
 
-    #[test]
-    fn should_setup_directory() {
-        let project = ProjectInfo {
+    fn project() -> ProjectInfo {
+        ProjectInfo {
             java_package_name: "com.test".to_string(),
-            lib_name: "myLib".to_string(),
+            lib_name: "my_lib".to_string(),
             lib_version: "1.0.0".to_string(),
-        };
+            integer_policy: Default::default(),
+            class_style: Default::default(),
+            panic_policy: Default::default(),
+        }
+    }
+
+    #[test]
+    fn should_setup_directory() {
+        let project = ProjectInfo { lib_name: "myLib".to_string(), ..project() };
         super::setup_java_project(
             &Path::new("."),
             &Path::new(".").join("target").join("setup-project-test"),
@@ -287,4 +821,367 @@ pub mod test {
         )
         .unwrap();
     }
+
+    #[test]
+    fn should_produce_getter_names() {
+        assert_eq!(super::java_getter_name("name", "String"), "getName");
+        assert_eq!(super::java_getter_name("visible", "boolean"), "isVisible");
+        assert_eq!(super::java_getter_name("isDone", "boolean"), "isDone");
+        assert_eq!(super::java_getter_name("island", "boolean"), "isIsland");
+        assert_eq!(super::java_getter_name("isDone", "String"), "getIsDone");
+        assert_eq!(super::java_getter_name("été", "String"), "getÉté");
+    }
+
+    #[test]
+    fn should_produce_handle_class() {
+        let project = project();
+        let mut ffi = FFIStore::default();
+        ffi.add_ffi_class(JavaFFIClass {
+            id: "Counter".to_string(),
+            kind: JavaFFIClassKind::Handle,
+            methods: vec![JavaFFIMethod {
+                id: "value".to_string(),
+                sig: "public synchronized native long value()".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        let classes = super::produce_java_classes(&project, &ffi);
+        let content = &classes[0].file_content;
+        assert!(content.contains("public class Counter implements AutoCloseable"));
+        assert!(content.contains("MyLib.loadNativeLibrary();"));
+        assert!(content.contains("\tpublic synchronized native long value();"));
+        assert!(content.contains("public synchronized native void close();"));
+    }
+
+    #[test]
+    fn should_produce_enum_class() {
+        let project = project();
+        let mut ffi = FFIStore::default();
+        ffi.add_ffi_class(JavaFFIClass {
+            id: "Color".to_string(),
+            kind: JavaFFIClassKind::Enum,
+            variants: vec![
+                JavaFFIVariant { id: "Red".to_string(), ..Default::default() },
+                JavaFFIVariant { id: "Green".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        });
+
+        let classes = super::produce_java_classes(&project, &ffi);
+        let content = &classes[0].file_content;
+        assert!(content.contains("public enum Color {\n\tRed,\n\tGreen\n}"));
+    }
+
+    #[test]
+    fn should_produce_sealed_interface() {
+        let project = project();
+        let mut ffi = FFIStore::default();
+        ffi.add_ffi_class(JavaFFIClass {
+            id: "Shape".to_string(),
+            kind: JavaFFIClassKind::Sealed,
+            variants: vec![
+                JavaFFIVariant {
+                    id: "Rect".to_string(),
+                    fields: vec![("w".to_string(), "double".to_string()), ("h".to_string(), "double".to_string())],
+                },
+                JavaFFIVariant { id: "Empty".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        });
+
+        let classes = super::produce_java_classes(&project, &ffi);
+        let content = &classes[0].file_content;
+        assert!(content.contains("public sealed interface Shape {"));
+        assert!(content.contains("\trecord Rect(double w, double h) implements Shape {}"));
+        assert!(content.contains("\trecord Empty() implements Shape {}"));
+
+        let pom = super::process_template("<source>[[java-version]]</source>", &project, &ffi, &classes);
+        assert_eq!(pom, "<source>17</source>");
+    }
+
+    #[test]
+    fn should_produce_record_and_fields_class() {
+        let project = project();
+        let mut ffi = FFIStore::default();
+        ffi.add_ffi_class(JavaFFIClass {
+            id: "Point".to_string(),
+            fields: vec![("x".to_string(), "int".to_string()), ("y".to_string(), "int".to_string())],
+            accessor: JavaFFIAccessor::Record,
+            field_accessors: vec![("y".to_string(), JavaFFIAccessor::Getter, "getY".to_string())],
+            ..Default::default()
+        });
+        ffi.add_ffi_class(JavaFFIClass {
+            id: "Label".to_string(),
+            fields: vec![
+                ("text".to_string(), "String".to_string()),
+                ("size".to_string(), "int".to_string()),
+                ("bold".to_string(), "boolean".to_string()),
+            ],
+            accessor: JavaFFIAccessor::Field,
+            field_accessors: vec![
+                ("text".to_string(), JavaFFIAccessor::Field, "label".to_string()),
+                ("size".to_string(), JavaFFIAccessor::Getter, "fontSize".to_string()),
+            ],
+            ..Default::default()
+        });
+
+        let classes = super::produce_java_classes(&project, &ffi);
+        let record = &classes[0].file_content;
+        assert!(record.contains("public record Point(int x, int y) {"));
+        assert!(record.contains("\tpublic int getY() {\n\t\treturn y;\n\t}"));
+
+        let class = &classes[1].file_content;
+        assert!(!class.contains("lombok"));
+        assert!(class.contains("public class Label {\n\tpublic String label;\n\tpublic int size;\n\tpublic boolean bold;"));
+        assert!(class.contains("\tpublic int fontSize() {\n\t\treturn size;\n\t}"));
+
+        let pom = super::process_template("<source>[[java-version]]</source>", &project, &ffi, &classes);
+        assert_eq!(pom, "<source>17</source>");
+    }
+
+    #[test]
+    fn should_produce_pojo_class() {
+        let project = ProjectInfo { class_style: ClassStyle::Pojo, ..project() };
+        let mut ffi = FFIStore::default();
+        ffi.add_ffi_class(JavaFFIClass {
+            id: "Node".to_string(),
+            fields: vec![
+                ("node_id".to_string(), "int".to_string()),
+                ("data".to_string(), "byte[]".to_string()),
+                ("active".to_string(), "boolean".to_string()),
+            ],
+            ..Default::default()
+        });
+
+        let classes = super::produce_java_classes(&project, &ffi);
+        let content = &classes[0].file_content;
+        assert!(!content.contains("lombok"));
+        assert!(content.contains("public class Node {\n\tprivate int node_id;\n\tprivate byte[] data;"));
+        assert!(content.contains(
+            "\tpublic Node(int node_id, byte[] data, boolean active) {\n\t\tthis.node_id = node_id;\n"
+        ));
+        assert!(content.contains("\tpublic int getNode_id() {\n\t\treturn node_id;\n\t}"));
+        assert!(content.contains("\tpublic boolean isActive() {"));
+        assert!(content.contains(
+            "return java.util.Objects.equals(node_id, other.node_id) && java.util.Arrays.equals(data, other.data)"
+        ));
+        assert!(content.contains("\t\tresult = 31 * result + java.util.Arrays.hashCode(data);\n"));
+        assert!(content.contains("return \"Node(node_id=\" + node_id + \", data=\" + java.util.Arrays.toString(data)"));
+
+        let pom = super::process_template("[[java-lombok-dependency]]", &project, &ffi, &classes);
+        assert_eq!(pom, "");
+        let project = ProjectInfo { class_style: ClassStyle::Lombok, ..project };
+        let pom = super::process_template("[[java-lombok-dependency]]", &project, &ffi, &classes);
+        assert!(pom.contains("<artifactId>lombok</artifactId>"));
+    }
+
+    #[test]
+    fn should_import_java_types() {
+        let project = project();
+        let mut ffi = FFIStore::default();
+        ffi.add_ffi_method(JavaFFIMethod {
+            id: "find".to_string(),
+            sig: "public static native Map<UUID, BigDecimal> find(Path dir, Instant since)".to_string(),
+            ..Default::default()
+        });
+        ffi.add_ffi_class(JavaFFIClass {
+            id: "Instant".to_string(),
+            kind: JavaFFIClassKind::Class,
+            fields: vec![("at".to_string(), "OffsetDateTime".to_string())],
+            ..Default::default()
+        });
+
+        let classes = super::produce_java_classes(&project, &ffi);
+        let content = &classes[0].file_content;
+        assert!(content.contains("import java.time.OffsetDateTime;"));
+
+        let lib = super::process_template("[[java-type-imports]]", &project, &ffi, &classes);
+        assert_eq!(
+            lib,
+            "import java.math.BigDecimal;\nimport java.nio.file.Path;\nimport java.util.UUID;"
+        );
+    }
+
+    #[test]
+    fn should_add_buffer_cleaner() {
+        let project = project();
+        let mut ffi = FFIStore::default();
+        let template = "<source>[[java-version]]</source>[[java-buffer-cleaner]]";
+        assert_eq!(super::process_template(template, &project, &ffi, &[]), "<source>1.8</source>");
+
+        ffi.add_ffi_method(JavaFFIMethod {
+            id: "render".to_string(),
+            sig: "public static native ByteBuffer render(int width)".to_string(),
+            ..Default::default()
+        });
+        let lib = super::process_template(template, &project, &ffi, &[]);
+        assert!(lib.starts_with("<source>9</source>"));
+        assert!(lib.contains("static final class DirectBufferCleaner implements Runnable"));
+        assert!(lib.contains("private static native void free(long address, long capacity);"));
+    }
+
+    #[test]
+    fn should_produce_classes_in_own_package() {
+        let project = project();
+        let mut ffi = FFIStore::default();
+        ffi.add_ffi_class(JavaFFIClass {
+            id: "ImageRequestDto".to_string(),
+            name: Some("ImageRequest".to_string()),
+            package: Some("com.acme.model".to_string()),
+            fields: vec![("size".to_string(), "Size".to_string())],
+            ..Default::default()
+        });
+        ffi.add_ffi_class(JavaFFIClass {
+            id: "Size".to_string(),
+            fields: vec![("width".to_string(), "int".to_string())],
+            ..Default::default()
+        });
+        ffi.add_ffi_method(JavaFFIMethod {
+            id: "resize".to_string(),
+            sig: "public static native ImageRequestDto resize(ImageRequestDto request)".to_string(),
+            class: Some("ImageOps".to_string()),
+            package: Some("com.acme".to_string()),
+            ..Default::default()
+        });
+        ffi.add_ffi_method(JavaFFIMethod {
+            id: "hello".to_string(),
+            sig: "public static native String hello(String input)".to_string(),
+            ..Default::default()
+        });
+        let ffi = super::with_java_names(&ffi, &project);
+
+        let classes = super::produce_java_classes(&project, &ffi);
+        let request = &classes[0];
+        assert_eq!(request.package_path, vec!["com", "acme", "model"]);
+        assert_eq!(request.file_name, "ImageRequest.java");
+        assert_eq!(request.class_import, "import com.acme.model.ImageRequest;");
+        assert!(request.file_content.starts_with("package com.acme.model;"));
+        assert!(request.file_content.contains("import com.test.Size;"));
+        assert!(request.file_content.contains("public class ImageRequest {"));
+
+        let facade = &classes[2];
+        assert_eq!(facade.package_path, vec!["com", "acme"]);
+        assert!(facade.file_content.contains("import com.acme.model.ImageRequest;\nimport com.test.MyLib;"));
+        assert!(facade.file_content.contains("public class ImageOps {"));
+        assert!(facade.file_content.contains("MyLib.loadNativeLibrary();"));
+        assert!(facade.file_content.contains("\tpublic static native ImageRequest resize(ImageRequest request);"));
+
+        let lib = super::process_template("[[java-bind-methods]]", &project, &ffi, &classes);
+        assert_eq!(lib, "\tpublic static native String hello(String input);");
+    }
+
+    #[test]
+    fn should_add_static_methods_to_handle_class() {
+        let project = project();
+        let mut ffi = FFIStore::default();
+        ffi.add_ffi_class(JavaFFIClass {
+            id: "RustCounter".to_string(),
+            name: Some("Counter".to_string()),
+            kind: JavaFFIClassKind::Handle,
+            ..Default::default()
+        });
+        ffi.add_ffi_method(JavaFFIMethod {
+            id: "counter_of".to_string(),
+            sig: "public static native RustCounter counter_of(long value)".to_string(),
+            class: Some("Counter".to_string()),
+            ..Default::default()
+        });
+        let ffi = super::with_java_names(&ffi, &project);
+
+        let classes = super::produce_java_classes(&project, &ffi);
+        assert_eq!(classes.len(), 1);
+        assert!(classes[0].file_content.contains("public class Counter implements AutoCloseable"));
+        assert!(classes[0].file_content.contains("\tpublic static native Counter counter_of(long value);"));
+    }
+
+    #[test]
+    fn should_produce_exception_class() {
+        let project = project();
+        let mut ffi = FFIStore::default();
+        ffi.add_ffi_class(JavaFFIClass {
+            id: "ImageError".to_string(),
+            kind: JavaFFIClassKind::Exception,
+            variants: vec![JavaFFIVariant { id: "Decode".to_string(), ..Default::default() }],
+            ..Default::default()
+        });
+        ffi.add_ffi_class(JavaFFIClass {
+            id: "IoFailure".to_string(),
+            kind: JavaFFIClassKind::Exception,
+            extends: Some("java.io.IOException".to_string()),
+            ..Default::default()
+        });
+
+        let classes = super::produce_java_classes(&project, &ffi);
+        let content = &classes[0].file_content;
+        assert!(content.contains("public class ImageError extends java.lang.RuntimeException {"));
+        assert!(content.contains("    public ImageError(String message) {\n        super(message);"));
+        assert!(content.contains("    public ImageError(String message, Throwable cause) {\n        super(message, cause);"));
+        assert!(content.contains(
+            "    public static class Decode extends ImageError {\n        public Decode(String message) {\n            super(message);\n        }\n\n        public Decode(String message, Throwable cause) {\n            super(message, cause);\n        }\n    }"
+        ));
+        assert!(classes[1].file_content.contains("public class IoFailure extends java.io.IOException {"));
+    }
+
+    #[test]
+    fn should_add_throws_clause() {
+        let project = project();
+        let mut ffi = FFIStore::default();
+        ffi.add_ffi_class(JavaFFIClass {
+            id: "ImageError".to_string(),
+            name: Some("ImageException".to_string()),
+            package: Some("com.acme".to_string()),
+            kind: JavaFFIClassKind::Exception,
+            ..Default::default()
+        });
+        ffi.add_ffi_method(JavaFFIMethod {
+            id: "decode".to_string(),
+            sig: "public static native byte[] decode(String path)".to_string(),
+            throws: vec!["java.io.IOException".to_string(), "ImageError".to_string(), "ParseError".to_string()],
+            ..Default::default()
+        });
+        // Rust type named IOException is not java.io.IOException (imported by the Lib class)
+        ffi.add_ffi_class(JavaFFIClass {
+            id: "IOException".to_string(),
+            kind: JavaFFIClassKind::Exception,
+            ..Default::default()
+        });
+        ffi.add_ffi_method(JavaFFIMethod {
+            id: "read".to_string(),
+            sig: "public static native byte[] read(String path)".to_string(),
+            throws: vec!["storage::IOException".to_string(), "std::io::Error".to_string()],
+            ..Default::default()
+        });
+        let ffi = super::with_java_names(&ffi, &project);
+
+        let classes = super::produce_java_classes(&project, &ffi);
+        let lib = super::process_template("[[java-bind-methods]]", &project, &ffi, &classes);
+        assert_eq!(
+            lib,
+            "\tpublic static native byte[] decode(String path) throws java.io.IOException, com.acme.ImageException;\n\
+             \tpublic static native byte[] read(String path) throws com.test.IOException;"
+        );
+        assert_eq!(classes[0].class_import, "import com.acme.ImageException;");
+    }
+
+    #[test]
+    fn should_rename_only_types_in_signatures() {
+        let project = project();
+        let mut ffi = FFIStore::default();
+        ffi.add_ffi_class(JavaFFIClass {
+            id: "Size".to_string(),
+            name: Some("Dimensions".to_string()),
+            ..Default::default()
+        });
+        ffi.add_ffi_method(JavaFFIMethod {
+            id: "scale".to_string(),
+            sig: "public static native Map<String, Size> scale(Size Size,List<Size> sizes)".to_string(),
+            ..Default::default()
+        });
+        let ffi = super::with_java_names(&ffi, &project);
+
+        let sig = &ffi.get_methods()[0].sig;
+        assert_eq!(sig, "public static native Map<String, Dimensions> scale(Dimensions Size,List<Dimensions> sizes)");
+    }
 }
@@ -1,6 +1,17 @@
 pub mod build {
     pub static JAVA_LIB_TEMPLATE: &str = include_str!("./build/Lib.java.template");
     pub static JAVA_CLASS_TEMPLATE: &str = include_str!("./build/Class.java.template");
+    pub static JAVA_POJO_TEMPLATE: &str = include_str!("./build/Pojo.java.template");
+    pub static JAVA_RECORD_TEMPLATE: &str = include_str!("./build/Record.java.template");
+    pub static JAVA_FIELDS_CLASS_TEMPLATE: &str = include_str!("./build/FieldsClass.java.template");
+    pub static JAVA_HANDLE_TEMPLATE: &str = include_str!("./build/Handle.java.template");
+    pub static JAVA_FACADE_TEMPLATE: &str = include_str!("./build/Facade.java.template");
+    pub static JAVA_PANIC_EXCEPTION_TEMPLATE: &str = include_str!("./build/RustPanicException.java.template");
+    pub static JAVA_ENUM_TEMPLATE: &str = include_str!("./build/Enum.java.template");
+    pub static JAVA_SEALED_TEMPLATE: &str = include_str!("./build/Sealed.java.template");
+    pub static JAVA_EXCEPTION_TEMPLATE: &str = include_str!("./build/Exception.java.template");
+    pub static JAVA_BUFFER_CLEANER_TEMPLATE: &str = include_str!("./build/BufferCleaner.java.template");
+    pub static JAVA_LOMBOK_DEPENDENCY_TEMPLATE: &str = include_str!("./build/LombokDependency.xml.template");
     pub static POM_TEMPLATE: &str = include_str!("./build/pom.xml.template");
 }
 
@@ -16,7 +16,7 @@ pub struct ImgSize {
 pub fn resizeImage<'a>(env: &mut JNIEnv<'a> , image: Vec<u8>, input_ext: String, output_ext: String, size: ImgSize) -> JResult<Vec<u8>> {
     let log = Log::init(env);
     log.info("Read image:", env);
-    let img = utils::read_image(image, &input_ext)?;
+    let img = utils::read_image(&image, &input_ext)?;
     log.info("Start resize:", env);
     let img = resize::resize(img, size.widht as u32, size.height as u32);
     log.info("Done resize:", env);
@@ -24,3 +24,12 @@ pub fn resizeImage<'a>(env: &mut JNIEnv<'a> , image: Vec<u8>, input_ext: String,
     log.info("To bytes", env);
     Ok(bytes)
 }
+
+// Zero-copy variant (direct ByteBuffer in, Rust-owned ByteBuffer out)
+#[java_bindgen]
+pub fn resizeImageBuffer<'a>(image: JDirectBuffer<'a>, input_ext: String, output_ext: String, size: ImgSize) -> JResult<JOwnedBuffer> {
+    let img = utils::read_image(&image, &input_ext)?;
+    let img = resize::resize(img, size.widht as u32, size.height as u32);
+    let bytes = utils::to_bytes(img, &output_ext)?;
+    Ok(JOwnedBuffer::from(bytes))
+}
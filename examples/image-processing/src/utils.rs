@@ -1,7 +1,7 @@
 use std::io::Cursor;
 use image::{io::Reader as ImageReader, DynamicImage, ImageResult};
 
-pub fn read_image(bytes: Vec<u8>, ext: &str) -> ImageResult<DynamicImage> {
+pub fn read_image(bytes: &[u8], ext: &str) -> ImageResult<DynamicImage> {
     let mut img = ImageReader::new(Cursor::new(bytes));
     let format = image::ImageFormat::from_extension(ext).unwrap_or(image::ImageFormat::Jpeg);
     img.set_format(format);
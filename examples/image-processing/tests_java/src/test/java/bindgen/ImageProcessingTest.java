@@ -5,7 +5,12 @@ import org.junit.jupiter.api.Test;
 import rs.image.ImageProcessing;
 import rs.image.ImgSize;
 
+import javax.imageio.ImageIO;
+import java.awt.image.BufferedImage;
 import java.io.*;
+import java.nio.ByteBuffer;
+
+import static org.junit.jupiter.api.Assertions.*;
 
 public class ImageProcessingTest {
 
@@ -37,5 +42,25 @@ public class ImageProcessingTest {
         save_image(image, "result_200x200.png");
     }
 
+    @Test
+    public void should_resize_direct_buffer() throws Exception {
+        byte[] bytes = fetch_resource("images/StockSnap_KAUFJW1PEQ_HD.jpg");
+        ByteBuffer input = ByteBuffer.allocateDirect(bytes.length);
+        input.put(bytes).flip();
+
+        ByteBuffer image = ImageProcessing.resizeImageBuffer(input, "jpg", "png", new ImgSize(200, 200));
+        assertTrue(image.isDirect());
+
+        byte[] result = new byte[image.remaining()];
+        image.get(result);
+        save_image(result, "result_buffer_200x200.png");
+
+        // Same image as the byte[] variant (aspect ratio kept)
+        assertArrayEquals(ImageProcessing.resizeImage(bytes, "jpg", "png", new ImgSize(200, 200)), result);
+        BufferedImage decoded = ImageIO.read(new ByteArrayInputStream(result));
+        assertEquals(200, Math.max(decoded.getWidth(), decoded.getHeight()));
+        assertTrue(Math.min(decoded.getWidth(), decoded.getHeight()) <= 200);
+    }
+
 }
 
@@ -9,12 +9,12 @@ pub struct ImageInput {
     pub ext: String,
 }
 
-#[allow(non_snake_case)]
 #[derive(Default, JavaClass)]
 pub struct TransformResult {
     pub image: Vec<u8>,
     pub ext: String,
-    pub appliedTransforms: JList<String>
+    #[java(rename = "appliedTransforms")]
+    pub applied_transforms: JList<String>
 }
 
 #[java_bindgen]
@@ -26,6 +26,6 @@ pub fn processImage(input: ImageInput, transforms: JList<String>) -> JResult<Tra
     Ok(TransformResult {
         image: utils::to_bytes(result, &input.ext)?,
         ext: input.ext,
-        appliedTransforms: JList(applied_transforms)
+        applied_transforms: JList(applied_transforms)
     })
 }
\ No newline at end of file
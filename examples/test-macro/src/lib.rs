@@ -661,16 +661,59 @@ pub mod throw_exception {
         Err(JExceptionClass::SecurityException.into())
     }
 
-    #[java_bindgen]
+    #[java_bindgen(throws = "java.io.IOException")]
     fn should_throw_exception_5<'a>(env: &mut JNIEnv<'a>, _nr: i32) -> JResult<i32> {
         Err(std::io::Error::other("Always Throw")).j_catch(env)?
     }
 
+    // LoadError -> ParseError -> std::io::Error (getCause() chain)
+    #[derive(Debug)]
+    struct ParseError(std::io::Error);
+
+    impl std::fmt::Display for ParseError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Parse failed")
+        }
+    }
+
+    impl std::error::Error for ParseError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[derive(Debug)]
+    struct LoadError(ParseError);
+
+    impl std::fmt::Display for LoadError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Load failed")
+        }
+    }
+
+    impl std::error::Error for LoadError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[java_bindgen]
+    fn should_throw_exception_chain<'a>(env: &mut JNIEnv<'a>, path: String) -> JResult<i32> {
+        let error = std::io::Error::new(std::io::ErrorKind::NotFound, path);
+        Err(LoadError(ParseError(error))).j_catch(env)?
+    }
+
     #[java_bindgen]
     fn should_throw_exception_in_order<'a>(env: &mut JNIEnv<'a>, _nr: i32) -> JResult<i32> {
         env.j_throw(JExceptionClass::IndexOutOfBoundsException);
         Err(JExceptionClass::UnsupportedOperationException.into())
     }
+
+    // RustPanicException
+    #[java_bindgen]
+    fn should_throw_panic(values: Vec<i32>, index: i32) -> JResult<i32> {
+        Ok(values[index as usize])
+    }
 }
 
 pub mod pass_list {
@@ -759,6 +802,802 @@ pub mod readme_examples {
     }
 }
 
+pub mod handle_types {
+    use java_bindgen::prelude::*;
+
+    pub struct Counter {
+        name: String,
+        value: i64,
+    }
+
+    #[java_bindgen]
+    impl Counter {
+        pub fn create(name: String) -> JResult<Self> {
+            Ok(Counter { name, value: 0 })
+        }
+
+        pub fn increment(&mut self, by: i64) -> JResult<i64> {
+            self.value += by;
+            Ok(self.value)
+        }
+
+        pub fn get_value(&self) -> JResult<i64> {
+            Ok(self.value)
+        }
+
+        pub fn get_name<'a>(&self, env: &mut JNIEnv<'a>) -> JResult<JString<'a>> {
+            self.name.as_str().into_java(env)
+        }
+
+        pub fn increment_after<'a>(&mut self, env: &mut JNIEnv<'a>, task: JCallback<()>) -> JResult<i64> {
+            task.call(env)?;
+            self.value += 1;
+            Ok(self.value)
+        }
+
+        pub fn fork(&self, name: String) -> JResult<Counter> {
+            Ok(Counter {
+                name,
+                value: self.value,
+            })
+        }
+    }
+}
+
+pub mod primitive_arrays {
+    use java_bindgen::prelude::*;
+
+    #[java_bindgen]
+    fn sum_ints(values: Vec<i32>) -> JResult<i64> {
+        Ok(values.iter().map(|v| *v as i64).sum())
+    }
+
+    #[java_bindgen]
+    fn scale_doubles(values: Vec<f64>, by: f64) -> JResult<Vec<f64>> {
+        Ok(values.into_iter().map(|v| v * by).collect())
+    }
+
+    #[java_bindgen]
+    fn pass_shorts(values: Vec<i16>) -> JResult<Vec<i16>> {
+        Ok(values)
+    }
+
+    #[java_bindgen]
+    fn pass_longs(values: Vec<i64>) -> JResult<Vec<i64>> {
+        Ok(values)
+    }
+
+    #[java_bindgen]
+    fn pass_floats(values: Vec<f32>) -> JResult<Vec<f32>> {
+        Ok(values)
+    }
+
+    #[java_bindgen]
+    fn pass_chars_u16(values: Vec<u16>) -> JResult<Vec<u16>> {
+        Ok(values)
+    }
+
+    #[java_bindgen]
+    fn reverse_chars(values: Vec<char>) -> JResult<Vec<char>> {
+        Ok(values.into_iter().rev().collect())
+    }
+
+    #[java_bindgen]
+    fn negate_booleans(values: Vec<bool>) -> JResult<Vec<bool>> {
+        Ok(values.into_iter().map(|v| !v).collect())
+    }
+
+    #[derive(Default, JavaClass)]
+    struct Samples {
+        values: Vec<f64>,
+        mask: Option<Vec<bool>>,
+    }
+
+    #[java_bindgen]
+    fn pass_samples(samples: Samples) -> JResult<Samples> {
+        Ok(samples)
+    }
+}
+
+pub mod object_arrays {
+    use java_bindgen::prelude::*;
+
+    #[derive(Default, JavaClass)]
+    struct Tag {
+        name: String,
+        labels: Vec<String>,
+    }
+
+    #[java_bindgen]
+    fn join_strings(values: Vec<String>, separator: String) -> JResult<String> {
+        Ok(values.join(&separator))
+    }
+
+    #[java_bindgen]
+    fn split_string(value: String) -> JResult<Vec<String>> {
+        Ok(value.split(',').map(|s| s.to_string()).collect())
+    }
+
+    #[java_bindgen]
+    fn pass_tags(tags: Vec<Tag>) -> JResult<Vec<Tag>> {
+        Ok(tags)
+    }
+
+    #[java_bindgen]
+    fn pass_string_matrix(matrix: Vec<Vec<String>>) -> JResult<Vec<Vec<String>>> {
+        Ok(matrix)
+    }
+
+    #[java_bindgen]
+    fn pass_boxed_ints(values: Vec<JInt>) -> JResult<Vec<JInt>> {
+        Ok(values)
+    }
+}
+
+pub mod maps {
+    use java_bindgen::prelude::*;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Default, JavaClass)]
+    struct Inventory {
+        owner: String,
+        items: HashMap<String, i32>,
+    }
+
+    #[java_bindgen]
+    fn count_words(text: String) -> JResult<HashMap<String, i32>> {
+        let mut counts = HashMap::new();
+        for word in text.split_whitespace() {
+            *counts.entry(word.to_string()).or_insert(0) += 1;
+        }
+        Ok(counts)
+    }
+
+    #[java_bindgen]
+    fn sorted_entries(values: HashMap<String, i64>) -> JResult<BTreeMap<String, i64>> {
+        Ok(values.into_iter().collect())
+    }
+
+    #[java_bindgen]
+    fn pass_map(values: JMap<i32, JList<String>>) -> JResult<JMap<i32, JList<String>>> {
+        Ok(values)
+    }
+
+    #[java_bindgen]
+    fn pass_inventory(inventory: Inventory) -> JResult<Inventory> {
+        Ok(inventory)
+    }
+}
+
+pub mod sets {
+    use java_bindgen::prelude::*;
+    use std::collections::{BTreeSet, HashSet};
+
+    #[derive(Default, JavaClass)]
+    struct Team {
+        name: String,
+        members: BTreeSet<String>,
+    }
+
+    #[java_bindgen]
+    fn unique_words(text: String) -> JResult<HashSet<String>> {
+        Ok(text.split_whitespace().map(|s| s.to_string()).collect())
+    }
+
+    #[java_bindgen]
+    fn sorted_ids(ids: HashSet<i32>) -> JResult<BTreeSet<i32>> {
+        Ok(ids.into_iter().collect())
+    }
+
+    #[java_bindgen]
+    fn pass_ordered_set(values: JSet<String>) -> JResult<JSet<String>> {
+        Ok(values)
+    }
+
+    #[java_bindgen]
+    fn pass_team(team: Team) -> JResult<Team> {
+        Ok(team)
+    }
+}
+
+pub mod integers {
+    use java_bindgen::prelude::*;
+
+    #[derive(Default, JavaClass)]
+    struct Counters {
+        small: u16,
+        hits: u32,
+        total: u64,
+        huge: u128,
+        delta: Option<i128>,
+    }
+
+    #[java_bindgen]
+    fn pass_u32(input: u32) -> JResult<u32> {
+        Ok(input)
+    }
+
+    #[java_bindgen]
+    fn max_u64() -> JResult<u64> {
+        Ok(u64::MAX)
+    }
+
+    #[java_bindgen]
+    fn len_usize(input: String) -> JResult<usize> {
+        Ok(input.len())
+    }
+
+    #[java_bindgen]
+    fn double_u128(input: u128) -> JResult<u128> {
+        input.checked_mul(2).ok_or_else(|| JExceptionClass::ArithmeticException.into())
+    }
+
+    #[java_bindgen]
+    fn pass_i128(input: i128) -> JResult<i128> {
+        Ok(input)
+    }
+
+    #[java_bindgen]
+    fn pass_unsigned_list(input: JList<u32>) -> JResult<JList<u32>> {
+        Ok(input)
+    }
+
+    #[java_bindgen]
+    fn widen_unsigned_array(input: Vec<u32>) -> JResult<Vec<u64>> {
+        Ok(input.into_iter().map(u64::from).collect())
+    }
+
+    #[java_bindgen]
+    fn pass_counters(input: Counters) -> JResult<Counters> {
+        Ok(input)
+    }
+}
+
+pub mod java_time {
+    use java_bindgen::prelude::*;
+    use std::time::Duration;
+
+    #[derive(Default, JavaClass)]
+    struct Event {
+        name: String,
+        at: chrono::DateTime<chrono::Utc>,
+        day: chrono::NaiveDate,
+        timeout: Option<Duration>,
+    }
+
+    #[java_bindgen]
+    fn add_seconds(at: chrono::DateTime<chrono::Utc>, seconds: i64) -> JResult<chrono::DateTime<chrono::Utc>> {
+        Ok(at + chrono::Duration::seconds(seconds))
+    }
+
+    #[java_bindgen]
+    fn pass_offset_date_time(at: chrono::DateTime<chrono::FixedOffset>) -> JResult<chrono::DateTime<chrono::FixedOffset>> {
+        Ok(at)
+    }
+
+    #[java_bindgen]
+    fn next_day(day: chrono::NaiveDate) -> JResult<chrono::NaiveDate> {
+        day.succ_opt().ok_or_else(|| JExceptionClass::ArithmeticException.into())
+    }
+
+    #[java_bindgen]
+    fn pass_local_date_time(at: chrono::NaiveDateTime) -> JResult<chrono::NaiveDateTime> {
+        Ok(at)
+    }
+
+    #[java_bindgen]
+    fn double_duration(duration: Duration) -> JResult<Duration> {
+        Ok(duration * 2)
+    }
+
+    #[java_bindgen]
+    fn pass_time_offset_date_time(at: time::OffsetDateTime) -> JResult<time::OffsetDateTime> {
+        Ok(at)
+    }
+
+    #[java_bindgen]
+    fn pass_time_date(day: time::Date) -> JResult<time::Date> {
+        Ok(day)
+    }
+
+    #[java_bindgen]
+    fn pass_time_date_time(at: time::PrimitiveDateTime) -> JResult<time::PrimitiveDateTime> {
+        Ok(at)
+    }
+
+    #[java_bindgen]
+    fn negate_time_duration(duration: time::Duration) -> JResult<time::Duration> {
+        Ok(-duration)
+    }
+
+    #[java_bindgen]
+    fn pass_event(event: Event) -> JResult<Event> {
+        Ok(event)
+    }
+}
+
+pub mod java_types {
+    use java_bindgen::prelude::*;
+    use rust_decimal::Decimal;
+    use std::path::PathBuf;
+    use uuid::Uuid;
+
+    #[derive(Default, JavaClass)]
+    struct Invoice {
+        id: Uuid,
+        total: Decimal,
+        file: Option<PathBuf>,
+    }
+
+    #[java_bindgen]
+    fn pass_uuid(id: Uuid) -> JResult<Uuid> {
+        Ok(id)
+    }
+
+    #[java_bindgen]
+    fn uuid_version(id: Uuid) -> JResult<i32> {
+        Ok(id.get_version_num() as i32)
+    }
+
+    #[java_bindgen]
+    fn add_decimals(a: Decimal, b: Decimal) -> JResult<Decimal> {
+        a.checked_add(b).ok_or_else(|| JExceptionClass::ArithmeticException.into())
+    }
+
+    #[java_bindgen]
+    fn pass_decimals(values: JList<Decimal>) -> JResult<JList<Decimal>> {
+        Ok(values)
+    }
+
+    #[java_bindgen]
+    fn file_name(path: PathBuf) -> JResult<String> {
+        Ok(path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default())
+    }
+
+    #[java_bindgen]
+    fn join_path(dir: PathBuf, file: String) -> JResult<PathBuf> {
+        Ok(dir.join(file))
+    }
+
+    #[java_bindgen]
+    fn pass_invoice(invoice: Invoice) -> JResult<Invoice> {
+        Ok(invoice)
+    }
+}
+
+pub mod buffers {
+    use java_bindgen::prelude::*;
+
+    #[java_bindgen]
+    fn sum_buffer(buffer: JDirectBuffer<'_>) -> JResult<i64> {
+        Ok(buffer.iter().map(|b| *b as i64).sum())
+    }
+
+    #[java_bindgen]
+    fn fill_buffer(buffer: JDirectBuffer<'_>, value: i8) -> JResult<()> {
+        let mut buffer = buffer;
+        buffer.as_mut_slice()?.fill(value as u8);
+        Ok(())
+    }
+
+    #[java_bindgen]
+    fn pass_direct_buffer<'a>(buffer: JDirectBuffer<'a>) -> JResult<JDirectBuffer<'a>> {
+        Ok(buffer)
+    }
+
+    #[java_bindgen]
+    fn owned_buffer(len: i32) -> JResult<JOwnedBuffer> {
+        let bytes: Vec<u8> = (0..len).map(|i| i as u8).collect();
+        Ok(JOwnedBuffer::from(bytes))
+    }
+
+    #[java_bindgen]
+    fn copy_buffer(buffer: JOwnedBuffer) -> JResult<JOwnedBuffer> {
+        Ok(buffer)
+    }
+}
+
+pub mod array_views {
+    use java_bindgen::prelude::*;
+
+    #[java_bindgen]
+    fn sum_int_slice(values: &[i32]) -> JResult<i64> {
+        Ok(values.iter().map(|v| *v as i64).sum())
+    }
+
+    #[java_bindgen]
+    fn dot(a: &[f64], b: &[f64]) -> JResult<f64> {
+        if a.len() != b.len() {
+            return Err(JExceptionClass::IllegalArgumentException.into());
+        }
+        Ok(a.iter().zip(b).map(|(a, b)| a * b).sum())
+    }
+
+    #[java_bindgen]
+    fn scale_floats(values: &mut [f32], factor: f32) -> JResult<()> {
+        values.iter_mut().for_each(|v| *v *= factor);
+        Ok(())
+    }
+
+    #[java_bindgen]
+    fn checksum(bytes: &[u8]) -> JResult<i64> {
+        Ok(bytes.iter().map(|b| *b as i64).sum())
+    }
+
+    #[java_bindgen]
+    fn invert_bytes(bytes: &mut [u8]) -> JResult<()> {
+        bytes.iter_mut().for_each(|b| *b = !*b);
+        Ok(())
+    }
+
+    #[java_bindgen]
+    fn fill_chars<'a>(env: &mut JNIEnv<'a>, chars: &mut [u16], text: String) -> JResult<i32> {
+        let _ = env;
+        let text: Vec<u16> = text.encode_utf16().collect();
+        let len = text.len().min(chars.len());
+        chars[..len].copy_from_slice(&text[..len]);
+        Ok(len as i32)
+    }
+}
+
+pub mod callbacks {
+    use java_bindgen::prelude::*;
+
+    #[java_bindgen]
+    fn count_down<'a>(env: &mut JNIEnv<'a>, steps: i32, progress: JCallback<(i32,)>) -> JResult<()> {
+        for step in (0..steps).rev() {
+            progress.call(env, step)?;
+        }
+        Ok(())
+    }
+
+    #[java_bindgen]
+    fn map_lengths<'a>(
+        env: &mut JNIEnv<'a>,
+        values: JList<String>,
+        f: JCallback<(String,), i32>,
+    ) -> JResult<JList<i32>> {
+        let mut result = JList(vec![]);
+        for value in values.0 {
+            result.add(f.call(env, value)?);
+        }
+        Ok(result)
+    }
+
+    #[java_bindgen]
+    fn supply_twice<'a>(env: &mut JNIEnv<'a>, supplier: JCallback<(), String>) -> JResult<String> {
+        let first = supplier.call(env)?;
+        let second = supplier.call(env)?;
+        Ok(format!("{first}{second}"))
+    }
+
+    #[java_bindgen]
+    fn fold_longs<'a>(
+        env: &mut JNIEnv<'a>,
+        values: Vec<i64>,
+        init: i64,
+        f: JCallback<(i64, i64), i64>,
+    ) -> JResult<i64> {
+        let mut acc = init;
+        for value in values {
+            acc = f.call(env, acc, value)?;
+        }
+        Ok(acc)
+    }
+
+    #[java_bindgen]
+    fn run_task<'a>(env: &mut JNIEnv<'a>, task: JCallback<()>) -> JResult<()> {
+        task.call(env)
+    }
+
+    #[java_bindgen]
+    fn send_pair<'a>(env: &mut JNIEnv<'a>, consumer: JCallback<(String, bool)>) -> JResult<()> {
+        consumer.call(env, "pair".to_string(), true)
+    }
+
+    #[java_bindgen]
+    fn catch_callback_error<'a>(env: &mut JNIEnv<'a>, f: JCallback<(i32,), i32>) -> JResult<String> {
+        match f.call(env, 1) {
+            Ok(value) => Ok(format!("ok: {value}")),
+            Err(err) => Ok(format!("{}: {err}", err.class)),
+        }
+    }
+
+    #[java_bindgen]
+    fn callback_exception_origin<'a>(env: &mut JNIEnv<'a>, task: JCallback<()>) -> JResult<String> {
+        let err = match task.call(env) {
+            Ok(()) => return Ok("ok".to_string()),
+            Err(err) => err,
+        };
+        let Some(java) = err.java_throwable() else {
+            return Err(err);
+        };
+        let frame = java.stack_trace.first().cloned().unwrap_or_default();
+        Ok(format!("{}: {err} at {frame}", java.class_name))
+    }
+}
+
+pub mod java_interfaces {
+    use java_bindgen::prelude::*;
+
+    #[java_interface(class = "bindgen.Inventory")]
+    pub trait Inventory {
+        fn count<'a>(&self, env: &mut JNIEnv<'a>, item: String) -> JResult<i32>;
+        fn add<'a>(&self, env: &mut JNIEnv<'a>, item: String, amount: i32) -> JResult<()>;
+        fn items<'a>(&self, env: &mut JNIEnv<'a>) -> JResult<JList<String>>;
+        fn is_empty<'a>(&self, env: &mut JNIEnv<'a>) -> JResult<bool>;
+    }
+
+    #[java_bindgen]
+    fn restock<'a>(env: &mut JNIEnv<'a>, inventory: JObject<'a>, min: i32) -> JResult<i32> {
+        let inventory: JInventory = inventory.into_rust(env)?;
+        let mut added = 0;
+        for item in inventory.items(env)?.0 {
+            let count = inventory.count(env, item.clone())?;
+            if count < min {
+                inventory.add(env, item, min - count)?;
+                added += min - count;
+            }
+        }
+        Ok(added)
+    }
+
+    #[java_bindgen]
+    fn inventory_summary<'a>(env: &mut JNIEnv<'a>, inventory: JObject<'a>) -> JResult<String> {
+        let inventory: JInventory = inventory.into_rust(env)?;
+        if inventory.is_empty(env)? {
+            return Ok("empty".to_string());
+        }
+        let mut summary = vec![];
+        for item in inventory.items(env)?.0 {
+            let count = inventory.count(env, item.clone())?;
+            summary.push(format!("{item}={count}"));
+        }
+        Ok(summary.join(", "))
+    }
+}
+
+pub mod enum_types {
+    use java_bindgen::prelude::*;
+
+    #[derive(Default, JavaClass)]
+    enum Color {
+        #[default]
+        Red,
+        Green,
+        Blue,
+    }
+
+    #[derive(Default, JavaClass)]
+    struct Pixel {
+        x: i32,
+        color: Color,
+        tint: Option<Color>,
+    }
+
+    #[java_bindgen]
+    fn next_color(color: Color) -> JResult<Color> {
+        Ok(match color {
+            Color::Red => Color::Green,
+            Color::Green => Color::Blue,
+            Color::Blue => Color::Red,
+        })
+    }
+
+    #[java_bindgen]
+    fn pass_pixel(pixel: Pixel) -> JResult<Pixel> {
+        Ok(pixel)
+    }
+
+    #[java_bindgen]
+    fn pass_colors(colors: JList<Color>) -> JResult<JList<Color>> {
+        Ok(colors)
+    }
+
+    #[derive(Default, JavaClass)]
+    enum Shape {
+        #[default]
+        Empty,
+        Circle {
+            r: f64,
+        },
+        Rect {
+            w: f64,
+            h: f64,
+        },
+        Labeled(String, Color),
+    }
+
+    #[java_bindgen]
+    fn scale_shape(shape: Shape, by: f64) -> JResult<Shape> {
+        Ok(match shape {
+            Shape::Empty => Shape::Empty,
+            Shape::Circle { r } => Shape::Circle { r: r * by },
+            Shape::Rect { w, h } => Shape::Rect { w: w * by, h: h * by },
+            Shape::Labeled(label, color) => Shape::Labeled(label, color),
+        })
+    }
+
+    #[java_bindgen]
+    fn pass_shapes(shapes: JList<Shape>) -> JResult<JList<Shape>> {
+        Ok(shapes)
+    }
+}
+
+pub mod class_accessors {
+    use java_bindgen::prelude::*;
+
+    // Java record: x(), y()
+    #[derive(Default, JavaClass)]
+    #[java(accessor = "record")]
+    struct Vec2 {
+        x: f64,
+        y: f64,
+    }
+
+    // Public fields, size read with fontSize()
+    #[derive(Default, JavaClass)]
+    #[java(accessor = "field")]
+    struct Label {
+        text: String,
+        #[java(accessor = "getter", name = "fontSize")]
+        size: i32,
+        visible: bool,
+    }
+
+    #[derive(Default, JavaClass)]
+    struct Track {
+        title: String,
+        #[java(accessor = "record", name = "lengthSeconds")]
+        length: i64,
+        #[java(accessor = "field")]
+        note: Option<String>,
+    }
+
+    #[java_bindgen]
+    fn scale_vec(v: Vec2, by: f64) -> JResult<Vec2> {
+        Ok(Vec2 {
+            x: v.x * by,
+            y: v.y * by,
+        })
+    }
+
+    #[java_bindgen]
+    fn shout_label(label: Label) -> JResult<Label> {
+        Ok(Label {
+            text: label.text.to_uppercase(),
+            size: label.size + 1,
+            visible: !label.visible,
+        })
+    }
+
+    #[java_bindgen]
+    fn pass_track(track: Track) -> JResult<Track> {
+        Ok(track)
+    }
+}
+
+pub mod field_attributes {
+    use java_bindgen::prelude::*;
+
+    #[derive(Default, JavaClass)]
+    #[java(rename_all = "camelCase")]
+    struct Upload {
+        file_name: String,
+        #[java(rename = "sizeInBytes")]
+        size_bytes: i64,
+        #[java(skip)]
+        checksum: String,
+        #[java(default)]
+        content_type: String,
+    }
+
+    #[java_bindgen]
+    fn normalize_upload(upload: Upload) -> JResult<Upload> {
+        let content_type = match upload.content_type.as_str() {
+            "" => "application/octet-stream".to_string(),
+            _ => upload.content_type,
+        };
+        Ok(Upload {
+            file_name: upload.file_name.to_lowercase(),
+            size_bytes: upload.size_bytes,
+            checksum: upload.checksum,
+            content_type,
+        })
+    }
+}
+
+pub mod class_packages {
+    use java_bindgen::prelude::*;
+
+    // com.test.macro.model.TextRequest
+    #[derive(Default, JavaClass)]
+    #[java(package = "com.test.macro.model", name = "TextRequest", accessor = "record")]
+    struct TextRequestDto {
+        text: String,
+        times: i32,
+    }
+
+    // com.test.macro.TextOps.repeat_text
+    #[java_bindgen(class = "TextOps")]
+    fn repeat_text(request: TextRequestDto) -> JResult<TextRequestDto> {
+        Ok(TextRequestDto {
+            text: request.text.repeat(request.times.max(0) as usize),
+            times: 1,
+        })
+    }
+}
+
+pub mod java_exceptions {
+    use java_bindgen::prelude::*;
+
+    // ImageError, ImageError.Decode, ImageError.TooLarge
+    #[derive(Debug, JavaException)]
+    enum ImageError {
+        Decode(std::io::Error),
+        TooLarge { width: i32 },
+    }
+
+    impl std::fmt::Display for ImageError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                ImageError::Decode(_) => write!(f, "Decode failed"),
+                ImageError::TooLarge { width } => write!(f, "Image too large: {width}"),
+            }
+        }
+    }
+
+    impl std::error::Error for ImageError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                ImageError::Decode(err) => Some(err),
+                ImageError::TooLarge { .. } => None,
+            }
+        }
+    }
+
+    #[java_bindgen]
+    fn check_image(data: Vec<u8>, width: i32) -> JResult<i32, ImageError> {
+        if data.is_empty() {
+            return Err(ImageError::Decode(std::io::Error::other("empty data")));
+        }
+        if width > 4096 {
+            return Err(ImageError::TooLarge { width });
+        }
+        Ok(width)
+    }
+
+    // Checked exception: throws ConfigError
+    #[derive(Debug, JavaException)]
+    #[java(extends = "java.lang.Exception")]
+    struct ConfigError(String);
+
+    impl std::fmt::Display for ConfigError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Invalid config: {}", self.0)
+        }
+    }
+
+    impl std::error::Error for ConfigError {}
+
+    #[java_bindgen]
+    fn parse_port(value: String) -> JResult<i32, ConfigError> {
+        value.parse().map_err(|_| ConfigError(value))
+    }
+
+    // std::io::Error: FileNotFoundException (NotFound)
+    #[java_bindgen(throws = "java.io.IOException")]
+    fn file_size(path: String) -> JResult<i64> {
+        let metadata = std::fs::metadata(path)?;
+        Ok(metadata.len() as i64)
+    }
+
+    #[java_bindgen]
+    fn throw_custom_class(class_name: String) -> JResult<()> {
+        Err(JException::from_class_and_msg(JExceptionClass::Custom(class_name), "custom"))
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use java_bindgen::prelude::*;
@@ -0,0 +1,41 @@
+package bindgen;
+
+import com.test.macro.TestMacro;
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class ArrayViewsTest {
+
+    @Test
+    public void should_borrow_array() {
+        assertEquals(2147483650L, TestMacro.sum_int_slice(new int[]{1, 2, Integer.MAX_VALUE}));
+        assertEquals(0, TestMacro.sum_int_slice(new int[]{}));
+        assertEquals(11.0, TestMacro.dot(new double[]{1, 2}, new double[]{3, 4}));
+        assertEquals(256, TestMacro.checksum(new byte[]{-1, 1}));
+    }
+
+    @Test
+    public void should_write_back_mutable_array() {
+        float[] floats = {1, 2.5f};
+        TestMacro.scale_floats(floats, 2);
+        assertArrayEquals(new float[]{2, 5}, floats);
+
+        byte[] bytes = {0, -1, 5};
+        TestMacro.invert_bytes(bytes);
+        assertArrayEquals(new byte[]{-1, 0, -6}, bytes);
+
+        char[] chars = new char[3];
+        assertEquals(3, TestMacro.fill_chars(chars, "hello"));
+        assertArrayEquals(new char[]{'h', 'e', 'l'}, chars);
+    }
+
+    @Test
+    public void should_release_array_on_exception() {
+        for (int i = 0; i < 10_000; i++) {
+            assertThrows(IllegalArgumentException.class, () -> TestMacro.dot(new double[]{1}, new double[]{2, 3}));
+        }
+        assertThrows(NullPointerException.class, () -> TestMacro.sum_int_slice(null));
+    }
+
+}
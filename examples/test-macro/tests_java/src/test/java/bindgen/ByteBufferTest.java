@@ -0,0 +1,82 @@
+package bindgen;
+
+import com.test.macro.TestMacro;
+import org.junit.jupiter.api.Test;
+
+import java.nio.ByteBuffer;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class ByteBufferTest {
+
+    @Test
+    public void should_borrow_direct_buffer() {
+        ByteBuffer buffer = ByteBuffer.allocateDirect(4);
+        buffer.put(new byte[]{1, 2, 3, 4}).flip();
+        assertEquals(10, TestMacro.sum_buffer(buffer));
+        assertSame(buffer, TestMacro.pass_direct_buffer(buffer));
+
+        // No copy: Rust writes into Java memory
+        TestMacro.fill_buffer(buffer, (byte) 7);
+        assertEquals(7, buffer.get(0));
+        assertEquals(7, buffer.get(3));
+    }
+
+    @Test
+    public void should_use_remaining_bytes() {
+        ByteBuffer buffer = ByteBuffer.allocateDirect(6);
+        buffer.put(new byte[]{1, 2, 3, 4, 5, 6});
+        buffer.position(1).limit(4);
+        assertEquals(9, TestMacro.sum_buffer(buffer));
+
+        TestMacro.fill_buffer(buffer, (byte) 7);
+        assertEquals(1, buffer.position());
+        assertEquals(4, buffer.limit());
+        buffer.clear();
+        assertEquals(1, buffer.get(0));
+        assertEquals(7, buffer.get(1));
+        assertEquals(7, buffer.get(3));
+        assertEquals(5, buffer.get(4));
+    }
+
+    @Test
+    public void should_not_write_read_only_buffer() {
+        ByteBuffer buffer = ByteBuffer.allocateDirect(4);
+        buffer.put(new byte[]{1, 2, 3, 4}).flip();
+        ByteBuffer readOnly = buffer.asReadOnlyBuffer();
+        assertEquals(10, TestMacro.sum_buffer(readOnly));
+        assertThrows(UnsupportedOperationException.class, () -> TestMacro.fill_buffer(readOnly, (byte) 7));
+        assertEquals(1, buffer.get(0));
+    }
+
+    @Test
+    public void should_reject_heap_buffer() {
+        assertThrows(IllegalArgumentException.class, () -> TestMacro.sum_buffer(ByteBuffer.allocate(4)));
+        assertThrows(NullPointerException.class, () -> TestMacro.sum_buffer(null));
+    }
+
+    @Test
+    public void should_return_rust_owned_buffer() {
+        ByteBuffer buffer = TestMacro.owned_buffer(5);
+        assertTrue(buffer.isDirect());
+        assertEquals(5, buffer.capacity());
+        assertEquals(4, buffer.get(4));
+        assertEquals(0, TestMacro.owned_buffer(0).capacity());
+
+        ByteBuffer copy = TestMacro.copy_buffer(buffer);
+        assertNotSame(buffer, copy);
+        assertEquals(buffer, copy);
+    }
+
+    @Test
+    public void should_free_rust_owned_buffers() {
+        // 1 GB in total, freed by the cleaner (Rust memory is not visible to the GC)
+        for (int i = 0; i < 1024; i++) {
+            assertEquals(1 << 20, TestMacro.owned_buffer(1 << 20).capacity());
+            if (i % 128 == 0) {
+                System.gc();
+            }
+        }
+    }
+
+}
@@ -0,0 +1,71 @@
+package bindgen;
+
+import com.test.macro.TestMacro;
+import org.junit.jupiter.api.Test;
+
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class CallbackTest {
+
+    @Test
+    public void should_call_consumer_and_runnable() {
+        List<Integer> steps = new ArrayList<>();
+        TestMacro.count_down(3, steps::add);
+        assertEquals(Arrays.asList(2, 1, 0), steps);
+
+        boolean[] ran = {false};
+        TestMacro.run_task(() -> ran[0] = true);
+        assertTrue(ran[0]);
+
+        String[] pair = {null};
+        TestMacro.send_pair((text, flag) -> pair[0] = text + flag);
+        assertEquals("pairtrue", pair[0]);
+    }
+
+    @Test
+    public void should_call_function_and_supplier() {
+        assertEquals(Arrays.asList(1, 3), TestMacro.map_lengths(Arrays.asList("a", "abc"), String::length));
+
+        int[] counter = {0};
+        assertEquals("x0x1", TestMacro.supply_twice(() -> "x" + counter[0]++));
+
+        long[] values = new long[10_000];
+        Arrays.fill(values, 1);
+        assertEquals(10_005, TestMacro.fold_longs(values, 5, Long::sum));
+    }
+
+    @Test
+    public void should_capture_callback_exception() {
+        assertEquals("ok", TestMacro.callback_exception_origin(() -> {}));
+        String origin = TestMacro.callback_exception_origin(() -> {
+            throw new IllegalStateException("boom");
+        });
+        assertTrue(origin.startsWith("java.lang.IllegalStateException: boom at bindgen.CallbackTest"));
+    }
+
+    @Test
+    public void should_return_callback_exception() {
+        assertEquals("ok: 2", TestMacro.catch_callback_error(x -> x + 1));
+        assertEquals("IllegalStateException: boom", TestMacro.catch_callback_error(x -> {
+            throw new IllegalStateException("boom");
+        }));
+
+        IllegalArgumentException e = assertThrows(IllegalArgumentException.class, () -> TestMacro.run_task(() -> {
+            throw new IllegalArgumentException("bad task");
+        }));
+        assertTrue(e.getMessage().contains("bad task"));
+
+        UnsupportedOperationException original = new UnsupportedOperationException("original");
+        assertSame(original, assertThrows(UnsupportedOperationException.class, () -> TestMacro.run_task(() -> {
+            throw original;
+        })));
+
+        assertThrows(NullPointerException.class, () -> TestMacro.run_task(null));
+        assertThrows(NullPointerException.class, () -> TestMacro.map_lengths(Arrays.asList("a"), s -> null));
+    }
+
+}
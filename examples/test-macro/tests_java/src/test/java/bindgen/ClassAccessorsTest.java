@@ -0,0 +1,44 @@
+package bindgen;
+
+import com.test.macro.Label;
+import com.test.macro.TestMacro;
+import com.test.macro.Track;
+import com.test.macro.Vec2;
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class ClassAccessorsTest {
+
+    @Test
+    public void should_pass_record() {
+        Vec2 result = TestMacro.scale_vec(new Vec2(1.5, -2), 2);
+        assertEquals(new Vec2(3, -4), result);
+        assertEquals(3, result.x());
+    }
+
+    @Test
+    public void should_pass_public_fields() {
+        Label label = new Label();
+        label.text = "hello";
+        label.size = 11;
+        label.visible = true;
+
+        Label result = TestMacro.shout_label(label);
+        assertEquals("HELLO", result.text);
+        assertEquals(12, result.fontSize());
+        assertFalse(result.visible);
+    }
+
+    @Test
+    public void should_pass_custom_accessors() {
+        Track track = new Track("Intro", 95L, "live");
+        Track result = TestMacro.pass_track(track);
+        assertEquals(track, result);
+        assertEquals(95L, result.lengthSeconds());
+        assertEquals("live", result.note);
+
+        Track plain = new Track("Outro", 30L, null);
+        assertNull(TestMacro.pass_track(plain).note);
+    }
+}
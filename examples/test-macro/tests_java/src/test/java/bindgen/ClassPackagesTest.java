@@ -0,0 +1,16 @@
+package bindgen;
+
+import com.test.macro.TextOps;
+import com.test.macro.model.TextRequest;
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class ClassPackagesTest {
+
+    @Test
+    public void should_call_facade_class() {
+        TextRequest result = TextOps.repeat_text(new TextRequest("ab", 3));
+        assertEquals(new TextRequest("ababab", 1), result);
+    }
+}
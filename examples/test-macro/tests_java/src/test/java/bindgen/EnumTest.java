@@ -0,0 +1,58 @@
+package bindgen;
+
+import com.test.macro.Color;
+import com.test.macro.Pixel;
+import com.test.macro.Shape;
+import com.test.macro.TestMacro;
+import org.junit.jupiter.api.Test;
+
+import java.util.Arrays;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class EnumTest {
+
+    @Test
+    public void should_pass_enum() {
+        assertEquals(Color.Green, TestMacro.next_color(Color.Red));
+        assertEquals(Color.Blue, TestMacro.next_color(Color.Green));
+        assertEquals(Color.Red, TestMacro.next_color(Color.Blue));
+    }
+
+    @Test
+    public void should_pass_enum_field() {
+        Pixel pixel = new Pixel(1, Color.Blue, null);
+        Pixel result = TestMacro.pass_pixel(pixel);
+        assertEquals(pixel, result);
+
+        Pixel tinted = new Pixel(2, Color.Red, Color.Green);
+        assertEquals(tinted, TestMacro.pass_pixel(tinted));
+    }
+
+    @Test
+    public void should_pass_enum_list() {
+        List<Color> colors = Arrays.asList(Color.Blue, Color.Red, Color.Blue);
+        assertEquals(colors, TestMacro.pass_colors(colors));
+    }
+
+    @Test
+    public void should_pass_sealed_interface() {
+        assertEquals(new Shape.Circle(3.0), TestMacro.scale_shape(new Shape.Circle(1.5), 2));
+        assertEquals(new Shape.Rect(3.0, 6.0), TestMacro.scale_shape(new Shape.Rect(1, 2), 3));
+        assertEquals(new Shape.Empty(), TestMacro.scale_shape(new Shape.Empty(), 3));
+        assertEquals(new Shape.Labeled("x", Color.Blue), TestMacro.scale_shape(new Shape.Labeled("x", Color.Blue), 3));
+    }
+
+    @Test
+    public void should_pass_sealed_interface_list() {
+        List<Shape> shapes = Arrays.asList(new Shape.Empty(), new Shape.Circle(2.0));
+        assertEquals(shapes, TestMacro.pass_shapes(shapes));
+    }
+
+    @Test
+    public void should_throw_on_null_sealed_interface() {
+        assertThrows(NullPointerException.class, () -> TestMacro.scale_shape(null, 1));
+    }
+
+}
@@ -2,8 +2,13 @@ package bindgen;
 
 import org.junit.jupiter.api.Test;
 
+import com.test.macro.RustPanicException;
 import com.test.macro.TestMacro;
 
+import java.io.FileNotFoundException;
+import java.io.IOException;
+import java.util.Arrays;
+
 import static org.junit.jupiter.api.Assertions.*;
 
 public class ExceptionsTest {
@@ -61,13 +66,15 @@ public class ExceptionsTest {
 
     @Test
     public void should_throw_exception_5() {
-        assertThrows(RuntimeException.class, () -> {
+        assertThrows(IOException.class, () -> {
             try {
                 TestMacro.should_throw_exception_5(1);
-            } catch (RuntimeException e) {
+            } catch (IOException e) {
                 System.out.println(e.getMessage());
-                assertTrue(e.toString().contains("Rust"));
-                assertTrue(e.toString().contains("Backtrace"));
+                // Rust backtrace frames (test_macro::throw_exception.should_throw_exception_5(./src/lib.rs))
+                assertTrue(Arrays.stream(e.getStackTrace()).anyMatch(f -> f.getFileName() != null && f.getFileName().endsWith(".rs")));
+                assertTrue(Arrays.stream(e.getStackTrace()).anyMatch(f -> f.getClassName().equals("test_macro::throw_exception")
+                        && f.getMethodName().equals("should_throw_exception_5")));
                 assertTrue(e.toString().contains("Always Throw"));
                 throw e;
             }
@@ -86,4 +93,27 @@ public class ExceptionsTest {
         });
     }
 
+    @Test
+    public void should_throw_cause_chain() {
+        RuntimeException e = assertThrows(RuntimeException.class, () -> TestMacro.should_throw_exception_chain("config.toml"));
+        assertEquals("Load failed", e.getMessage());
+
+        Throwable parse = e.getCause();
+        assertEquals(RuntimeException.class, parse.getClass());
+        assertEquals("Parse failed", parse.getMessage());
+
+        Throwable io = parse.getCause();
+        assertInstanceOf(FileNotFoundException.class, io);
+        assertEquals("config.toml", io.getMessage());
+        assertNull(io.getCause());
+    }
+
+    @Test
+    public void should_throw_panic() {
+        assertEquals(2, TestMacro.should_throw_panic(new int[]{1, 2}, 1));
+        RustPanicException e = assertThrows(RustPanicException.class, () -> TestMacro.should_throw_panic(new int[]{1, 2}, 5));
+        assertTrue(e.getMessage().contains("Rust panic: index out of bounds"));
+        assertTrue(e.getMessage().contains("src/lib.rs"));
+    }
+
 }
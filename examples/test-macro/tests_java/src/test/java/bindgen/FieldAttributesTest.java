@@ -0,0 +1,24 @@
+package bindgen;
+
+import com.test.macro.TestMacro;
+import com.test.macro.Upload;
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class FieldAttributesTest {
+
+    @Test
+    public void should_rename_fields() {
+        Upload result = TestMacro.normalize_upload(new Upload("Report.PDF", 2048L, "application/pdf"));
+        assertEquals("report.pdf", result.getFileName());
+        assertEquals(2048L, result.getSizeInBytes());
+        assertEquals("application/pdf", result.getContentType());
+    }
+
+    @Test
+    public void should_use_default_for_null() {
+        Upload result = TestMacro.normalize_upload(new Upload("a.bin", 1L, null));
+        assertEquals("application/octet-stream", result.getContentType());
+    }
+}
@@ -0,0 +1,51 @@
+package bindgen;
+
+import com.test.macro.Counter;
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class HandleTest {
+
+    @Test
+    public void should_keep_state_between_calls() {
+        try (Counter counter = Counter.create("counter")) {
+            assertEquals(5, counter.increment(5));
+            assertEquals(7, counter.increment(2));
+            assertEquals(7, counter.get_value());
+            assertEquals("counter", counter.get_name());
+        }
+    }
+
+    @Test
+    public void should_return_new_handle() {
+        try (Counter counter = Counter.create("first")) {
+            counter.increment(3);
+            try (Counter fork = counter.fork("second")) {
+                fork.increment(1);
+                assertEquals("second", fork.get_name());
+                assertEquals(4, fork.get_value());
+                assertEquals(3, counter.get_value());
+            }
+        }
+    }
+
+    @Test
+    public void should_throw_when_closed() {
+        Counter counter = Counter.create("closed");
+        counter.close();
+        counter.close();
+        assertThrows(IllegalStateException.class, counter::get_value);
+    }
+
+    @Test
+    public void should_not_close_borrowed_handle() {
+        try (Counter counter = Counter.create("borrowed")) {
+            IllegalStateException e = assertThrows(IllegalStateException.class, () -> counter.increment_after(counter::close));
+            assertTrue(e.getMessage().contains("Handle is borrowed"));
+            assertEquals(0, counter.get_value());
+            assertEquals(1, counter.increment_after(() -> {}));
+        }
+    }
+
+}
@@ -0,0 +1,49 @@
+package bindgen;
+
+import com.test.macro.Counters;
+import com.test.macro.TestMacro;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigInteger;
+import java.util.Arrays;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class IntegersTest {
+
+    @Test
+    public void should_keep_same_width() {
+        assertEquals("4294967295", Integer.toUnsignedString(TestMacro.pass_u32(-1)));
+        assertEquals("18446744073709551615", Long.toUnsignedString(TestMacro.max_u64()));
+        assertEquals(5L, TestMacro.len_usize("hello"));
+        assertEquals(Arrays.asList(1, -1), TestMacro.pass_unsigned_list(Arrays.asList(1, -1)));
+        assertArrayEquals(new long[]{1L, 4294967295L}, TestMacro.widen_unsigned_array(new int[]{1, -1}));
+    }
+
+    @Test
+    public void should_map_wide_integers_to_big_integer() {
+        BigInteger max = new BigInteger("170141183460469231731687303715884105727");
+        assertEquals(max.multiply(BigInteger.TWO), TestMacro.double_u128(max));
+        BigInteger min = new BigInteger("-170141183460469231731687303715884105728");
+        assertEquals(min, TestMacro.pass_i128(min));
+    }
+
+    @Test
+    public void should_throw_on_out_of_range() {
+        assertThrows(ArithmeticException.class, () -> TestMacro.double_u128(BigInteger.valueOf(-1)));
+        assertThrows(ArithmeticException.class, () -> TestMacro.double_u128(BigInteger.TWO.pow(128)));
+        assertThrows(NullPointerException.class, () -> TestMacro.double_u128(null));
+    }
+
+    @Test
+    public void should_pass_integer_fields() {
+        Counters counters = new Counters((char) 65535, -1, -1L, BigInteger.TEN, BigInteger.valueOf(-3));
+        Counters result = TestMacro.pass_counters(counters);
+        assertEquals(65535, result.getSmall());
+        assertEquals(-1, result.getHits());
+        assertEquals(-1L, result.getTotal());
+        assertEquals(BigInteger.TEN, result.getHuge());
+        assertEquals(BigInteger.valueOf(-3), result.getDelta());
+    }
+
+}
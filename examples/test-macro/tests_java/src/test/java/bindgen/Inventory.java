@@ -0,0 +1,16 @@
+package bindgen;
+
+import java.util.List;
+
+// Implemented in Java, called from Rust (#[java_interface])
+public interface Inventory {
+
+    int count(String item);
+
+    void add(String item, int amount);
+
+    List<String> items();
+
+    boolean isEmpty();
+
+}
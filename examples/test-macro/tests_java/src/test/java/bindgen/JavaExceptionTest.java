@@ -0,0 +1,43 @@
+package bindgen;
+
+import com.test.macro.ConfigError;
+import com.test.macro.ImageError;
+import com.test.macro.TestMacro;
+import org.junit.jupiter.api.Test;
+
+import java.io.FileNotFoundException;
+import java.io.UncheckedIOException;
+import java.util.concurrent.CancellationException;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class JavaExceptionTest {
+
+    @Test
+    public void should_throw_variant_class() {
+        assertEquals(64, TestMacro.check_image(new byte[]{1}, 64));
+
+        ImageError.TooLarge tooLarge = assertThrows(ImageError.TooLarge.class, () -> TestMacro.check_image(new byte[]{1}, 5000));
+        assertTrue(tooLarge.getMessage().contains("Image too large: 5000"));
+
+        ImageError decode = assertThrows(ImageError.class, () -> TestMacro.check_image(new byte[]{}, 64));
+        assertInstanceOf(ImageError.Decode.class, decode);
+        assertEquals("Decode failed", decode.getMessage());
+        assertEquals("empty data", decode.getCause().getMessage());
+    }
+
+    @Test
+    public void should_declare_checked_exceptions() throws Exception {
+        assertEquals(8080, TestMacro.parse_port("8080"));
+        ConfigError e = assertThrows(ConfigError.class, () -> TestMacro.parse_port("http"));
+        assertTrue(e.getMessage().contains("Invalid config: http"));
+
+        assertThrows(FileNotFoundException.class, () -> TestMacro.file_size("/not/existing/file"));
+    }
+
+    @Test
+    public void should_throw_any_class() {
+        assertThrows(UncheckedIOException.class, () -> TestMacro.throw_custom_class("java.io.UncheckedIOException"));
+        assertThrows(CancellationException.class, () -> TestMacro.throw_custom_class("java.util.concurrent.CancellationException"));
+    }
+}
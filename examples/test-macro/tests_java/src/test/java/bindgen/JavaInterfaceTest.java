@@ -0,0 +1,72 @@
+package bindgen;
+
+import com.test.macro.TestMacro;
+import org.junit.jupiter.api.Test;
+
+import java.util.ArrayList;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class JavaInterfaceTest {
+
+    static class MapInventory implements Inventory {
+        final Map<String, Integer> stock = new LinkedHashMap<>();
+
+        @Override
+        public int count(String item) {
+            return stock.getOrDefault(item, 0);
+        }
+
+        @Override
+        public void add(String item, int amount) {
+            if (amount <= 0) {
+                throw new IllegalArgumentException("amount must be positive");
+            }
+            stock.merge(item, amount, Integer::sum);
+        }
+
+        @Override
+        public List<String> items() {
+            return new ArrayList<>(stock.keySet());
+        }
+
+        @Override
+        public boolean isEmpty() {
+            return stock.isEmpty();
+        }
+    }
+
+    @Test
+    public void should_call_java_object() {
+        MapInventory inventory = new MapInventory();
+        assertEquals("empty", TestMacro.inventory_summary(inventory));
+
+        inventory.stock.put("apple", 2);
+        inventory.stock.put("pear", 7);
+        assertEquals(3, TestMacro.restock(inventory, 5));
+        assertEquals("apple=5, pear=7", TestMacro.inventory_summary(inventory));
+    }
+
+    @Test
+    public void should_check_java_object() {
+        assertThrows(NullPointerException.class, () -> TestMacro.restock(null, 1));
+        assertThrows(ClassCastException.class, () -> TestMacro.restock("not an inventory", 1));
+    }
+
+    @Test
+    public void should_return_java_exception() {
+        Inventory broken = new MapInventory() {
+            @Override
+            public void add(String item, int amount) {
+                throw new IllegalStateException("read only");
+            }
+        };
+        ((MapInventory) broken).stock.put("apple", 0);
+        IllegalStateException e = assertThrows(IllegalStateException.class, () -> TestMacro.restock(broken, 1));
+        assertTrue(e.getMessage().contains("read only"));
+    }
+
+}
@@ -0,0 +1,59 @@
+package bindgen;
+
+import com.test.macro.Event;
+import com.test.macro.TestMacro;
+import org.junit.jupiter.api.Test;
+
+import java.time.*;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class JavaTimeTest {
+
+    @Test
+    public void should_pass_chrono_types() {
+        Instant instant = Instant.parse("2024-02-28T23:59:30.123456789Z");
+        assertEquals(Instant.parse("2024-02-29T00:00:30.123456789Z"), TestMacro.add_seconds(instant, 60));
+
+        OffsetDateTime offset = OffsetDateTime.parse("2024-05-01T10:15:30.5+02:00");
+        assertEquals(offset, TestMacro.pass_offset_date_time(offset));
+
+        assertEquals(LocalDate.of(2024, 2, 29), TestMacro.next_day(LocalDate.of(2024, 2, 28)));
+
+        LocalDateTime local = LocalDateTime.of(2024, 1, 2, 3, 4, 5, 6);
+        assertEquals(local, TestMacro.pass_local_date_time(local));
+    }
+
+    @Test
+    public void should_pass_time_types() {
+        OffsetDateTime offset = OffsetDateTime.parse("2024-05-01T10:15:30.5-03:30");
+        assertEquals(offset, TestMacro.pass_time_offset_date_time(offset));
+        assertEquals(LocalDate.of(2024, 12, 31), TestMacro.pass_time_date(LocalDate.of(2024, 12, 31)));
+
+        LocalDateTime local = LocalDateTime.of(2024, 1, 2, 3, 4, 5, 6);
+        assertEquals(local, TestMacro.pass_time_date_time(local));
+        assertEquals(Duration.ofMillis(-1500), TestMacro.negate_time_duration(Duration.ofMillis(1500)));
+    }
+
+    @Test
+    public void should_pass_duration() {
+        assertEquals(Duration.ofSeconds(3), TestMacro.double_duration(Duration.ofMillis(1500)));
+        assertThrows(ArithmeticException.class, () -> TestMacro.double_duration(Duration.ofSeconds(-1)));
+    }
+
+    @Test
+    public void should_throw_on_out_of_range() {
+        assertThrows(ArithmeticException.class, () -> TestMacro.next_day(LocalDate.of(300000, 1, 1)));
+        assertThrows(NullPointerException.class, () -> TestMacro.next_day(null));
+    }
+
+    @Test
+    public void should_pass_java_time_fields() {
+        Event event = new Event("launch", Instant.ofEpochSecond(1), LocalDate.of(2024, 1, 1), null);
+        Event result = TestMacro.pass_event(event);
+        assertEquals(Instant.ofEpochSecond(1), result.getAt());
+        assertEquals(LocalDate.of(2024, 1, 1), result.getDay());
+        assertNull(result.getTimeout());
+    }
+
+}
@@ -0,0 +1,66 @@
+package bindgen;
+
+import com.test.macro.Invoice;
+import com.test.macro.TestMacro;
+import org.junit.jupiter.api.Test;
+
+import java.math.BigDecimal;
+import java.math.BigInteger;
+import java.nio.file.Paths;
+import java.util.Arrays;
+import java.util.List;
+import java.util.UUID;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class JavaTypesTest {
+
+    @Test
+    public void should_pass_uuid() {
+        UUID id = UUID.fromString("f81d4fae-7dec-11d0-a765-00a0c91e6bf6");
+        assertEquals(id, TestMacro.pass_uuid(id));
+        assertEquals(1, TestMacro.uuid_version(id));
+
+        UUID random = UUID.randomUUID();
+        assertEquals(random, TestMacro.pass_uuid(random));
+        assertEquals(4, TestMacro.uuid_version(random));
+        assertThrows(NullPointerException.class, () -> TestMacro.pass_uuid(null));
+    }
+
+    @Test
+    public void should_pass_big_decimal() {
+        assertEquals(new BigDecimal("3.75"), TestMacro.add_decimals(new BigDecimal("1.5"), new BigDecimal("2.25")));
+        assertEquals(new BigDecimal("-0.001"), TestMacro.add_decimals(new BigDecimal("-0.001"), BigDecimal.ZERO));
+        assertEquals(new BigDecimal("1200"), TestMacro.add_decimals(new BigDecimal("1.2E+3"), BigDecimal.ZERO));
+
+        List<BigDecimal> values = Arrays.asList(new BigDecimal("0.1"), new BigDecimal("-79228162514264337593543950335"));
+        assertEquals(values, TestMacro.pass_decimals(values));
+    }
+
+    @Test
+    public void should_throw_on_decimal_out_of_range() {
+        BigDecimal tooBig = new BigDecimal(BigInteger.valueOf(2).pow(96));
+        assertThrows(ArithmeticException.class, () -> TestMacro.add_decimals(tooBig, BigDecimal.ZERO));
+        assertThrows(ArithmeticException.class, () -> TestMacro.add_decimals(new BigDecimal("1E-29"), BigDecimal.ZERO));
+    }
+
+    @Test
+    public void should_pass_path() {
+        assertEquals("lib.rs", TestMacro.file_name(Paths.get("src", "lib.rs")));
+        assertEquals(Paths.get("target", "out.txt"), TestMacro.join_path(Paths.get("target"), "out.txt"));
+    }
+
+    @Test
+    public void should_pass_java_types_fields() {
+        UUID id = UUID.randomUUID();
+        Invoice invoice = new Invoice(id, new BigDecimal("19.99"), Paths.get("invoice.pdf"));
+        Invoice result = TestMacro.pass_invoice(invoice);
+        assertEquals(id, result.getId());
+        assertEquals(new BigDecimal("19.99"), result.getTotal());
+        assertEquals(Paths.get("invoice.pdf"), result.getFile());
+
+        Invoice empty = TestMacro.pass_invoice(new Invoice(id, BigDecimal.ZERO, null));
+        assertNull(empty.getFile());
+    }
+
+}
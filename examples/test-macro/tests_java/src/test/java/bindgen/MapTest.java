@@ -0,0 +1,55 @@
+package bindgen;
+
+import com.test.macro.Inventory;
+import com.test.macro.TestMacro;
+import org.junit.jupiter.api.Test;
+
+import java.util.*;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class MapTest {
+
+    @Test
+    public void should_return_map() {
+        Map<String, Integer> counts = TestMacro.count_words("a b a c a");
+        assertEquals(3, counts.size());
+        assertEquals(3, counts.get("a"));
+        assertEquals(1, counts.get("b"));
+    }
+
+    @Test
+    public void should_keep_btree_map_order() {
+        Map<String, Long> input = new HashMap<>();
+        input.put("z", 1L);
+        input.put("a", 2L);
+        input.put("m", 3L);
+        Map<String, Long> sorted = TestMacro.sorted_entries(input);
+        assertInstanceOf(LinkedHashMap.class, sorted);
+        assertEquals(Arrays.asList("a", "m", "z"), new ArrayList<>(sorted.keySet()));
+    }
+
+    @Test
+    public void should_pass_nested_map() {
+        Map<Integer, List<String>> input = new TreeMap<>();
+        input.put(1, Arrays.asList("x", "y"));
+        input.put(2, new ArrayList<>());
+        assertEquals(input, TestMacro.pass_map(input));
+        assertEquals(new HashMap<>(), TestMacro.pass_map(new HashMap<>()));
+    }
+
+    @Test
+    public void should_pass_map_field() {
+        Map<String, Integer> items = new HashMap<>();
+        items.put("apple", 2);
+        Inventory result = TestMacro.pass_inventory(new Inventory("Tom", items));
+        assertEquals("Tom", result.getOwner());
+        assertEquals(items, result.getItems());
+    }
+
+    @Test
+    public void should_throw_on_null_map() {
+        assertThrows(NullPointerException.class, () -> TestMacro.pass_map(null));
+    }
+
+}
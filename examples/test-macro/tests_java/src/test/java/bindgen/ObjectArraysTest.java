@@ -0,0 +1,41 @@
+package bindgen;
+
+import com.test.macro.Tag;
+import com.test.macro.TestMacro;
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class ObjectArraysTest {
+
+    @Test
+    public void should_pass_string_array() {
+        assertEquals("a-b-c", TestMacro.join_strings(new String[]{"a", "b", "c"}, "-"));
+        assertArrayEquals(new String[]{"x", "y", "z"}, TestMacro.split_string("x,y,z"));
+    }
+
+    @Test
+    public void should_pass_class_array() {
+        Tag[] tags = new Tag[]{
+                new Tag("first", new String[]{"a", "b"}),
+                new Tag("second", new String[]{})
+        };
+        Tag[] result = TestMacro.pass_tags(tags);
+        assertEquals(2, result.length);
+        assertEquals("first", result[0].getName());
+        assertArrayEquals(new String[]{"a", "b"}, result[0].getLabels());
+        assertArrayEquals(new String[]{}, result[1].getLabels());
+    }
+
+    @Test
+    public void should_pass_nested_arrays() {
+        String[][] matrix = new String[][]{{"a"}, {"b", "c"}, {}};
+        assertArrayEquals(matrix, TestMacro.pass_string_matrix(matrix));
+    }
+
+    @Test
+    public void should_pass_boxed_array() {
+        assertArrayEquals(new Integer[]{1, 2}, TestMacro.pass_boxed_ints(new Integer[]{1, 2}));
+    }
+
+}
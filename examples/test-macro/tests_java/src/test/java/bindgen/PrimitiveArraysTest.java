@@ -0,0 +1,52 @@
+package bindgen;
+
+import com.test.macro.Samples;
+import com.test.macro.TestMacro;
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class PrimitiveArraysTest {
+
+    @Test
+    public void should_pass_int_array() {
+        assertEquals(6, TestMacro.sum_ints(new int[]{1, 2, 3}));
+        assertEquals(0, TestMacro.sum_ints(new int[]{}));
+    }
+
+    @Test
+    public void should_return_double_array() {
+        assertArrayEquals(new double[]{2.0, 4.5}, TestMacro.scale_doubles(new double[]{1.0, 2.25}, 2.0));
+    }
+
+    @Test
+    public void should_pass_primitive_arrays() {
+        assertArrayEquals(new short[]{1, -2, Short.MAX_VALUE}, TestMacro.pass_shorts(new short[]{1, -2, Short.MAX_VALUE}));
+        assertArrayEquals(new long[]{Long.MIN_VALUE, 0, Long.MAX_VALUE}, TestMacro.pass_longs(new long[]{Long.MIN_VALUE, 0, Long.MAX_VALUE}));
+        assertArrayEquals(new float[]{1.5f, -0.25f}, TestMacro.pass_floats(new float[]{1.5f, -0.25f}));
+        assertArrayEquals(new char[]{'a', '\uD83D'}, TestMacro.pass_chars_u16(new char[]{'a', '\uD83D'}));
+    }
+
+    @Test
+    public void should_pass_char_array() {
+        assertArrayEquals(new char[]{'c', 'b', 'a'}, TestMacro.reverse_chars(new char[]{'a', 'b', 'c'}));
+        assertThrows(ClassCastException.class, () -> TestMacro.reverse_chars(new char[]{'\uD83D'}));
+    }
+
+    @Test
+    public void should_pass_boolean_array() {
+        assertArrayEquals(new boolean[]{false, true}, TestMacro.negate_booleans(new boolean[]{true, false}));
+    }
+
+    @Test
+    public void should_pass_array_fields() {
+        Samples samples = new Samples(new double[]{1.0, 2.0}, null);
+        Samples result = TestMacro.pass_samples(samples);
+        assertArrayEquals(samples.getValues(), result.getValues());
+        assertNull(result.getMask());
+
+        Samples masked = new Samples(new double[]{3.0}, new boolean[]{true});
+        assertArrayEquals(new boolean[]{true}, TestMacro.pass_samples(masked).getMask());
+    }
+
+}
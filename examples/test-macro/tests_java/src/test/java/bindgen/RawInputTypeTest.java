@@ -5,6 +5,7 @@ import org.junit.jupiter.api.Test;
 import com.test.macro.TestMacro;
 
 import java.util.ArrayList;
+import java.util.Arrays;
 import java.util.List;
 
 import static org.junit.jupiter.api.Assertions.*;
@@ -31,7 +32,8 @@ public class RawInputTypeTest {
                 assertEquals("ok", s);
             } catch (UnsupportedOperationException e) {
                 System.out.println(e);
-                assertTrue(e.toString().contains("Rust"));
+                // Rust backtrace frames (test_macro::raw_input_type.raw_input_type_2(./src/lib.rs))
+                assertTrue(Arrays.stream(e.getStackTrace()).anyMatch(f -> f.getFileName() != null && f.getFileName().endsWith(".rs")));
                 assertTrue(e.toString().contains("Cast"));
                 throw e;
             }
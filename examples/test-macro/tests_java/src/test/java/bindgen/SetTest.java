@@ -0,0 +1,47 @@
+package bindgen;
+
+import com.test.macro.Team;
+import com.test.macro.TestMacro;
+import org.junit.jupiter.api.Test;
+
+import java.util.*;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+public class SetTest {
+
+    @Test
+    public void should_return_set() {
+        Set<String> words = TestMacro.unique_words("a b a c");
+        assertEquals(new HashSet<>(Arrays.asList("a", "b", "c")), words);
+    }
+
+    @Test
+    public void should_keep_btree_set_order() {
+        Set<Integer> sorted = TestMacro.sorted_ids(new HashSet<>(Arrays.asList(30, 1, 20)));
+        assertInstanceOf(LinkedHashSet.class, sorted);
+        assertEquals(Arrays.asList(1, 20, 30), new ArrayList<>(sorted));
+    }
+
+    @Test
+    public void should_keep_linked_hash_set_order() {
+        Set<String> ordered = new LinkedHashSet<>(Arrays.asList("z", "a", "m"));
+        Set<String> result = TestMacro.pass_ordered_set(ordered);
+        assertEquals(Arrays.asList("z", "a", "m"), new ArrayList<>(result));
+        assertTrue(TestMacro.pass_ordered_set(new HashSet<>()).isEmpty());
+    }
+
+    @Test
+    public void should_pass_set_field() {
+        Set<String> members = new HashSet<>(Arrays.asList("Tom", "Anna"));
+        Team result = TestMacro.pass_team(new Team("core", members));
+        assertEquals("core", result.getName());
+        assertEquals(Arrays.asList("Anna", "Tom"), new ArrayList<>(result.getMembers()));
+    }
+
+    @Test
+    public void should_throw_on_null_set() {
+        assertThrows(NullPointerException.class, () -> TestMacro.pass_ordered_set(null));
+    }
+
+}
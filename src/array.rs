@@ -0,0 +1,48 @@
+#![allow(unsafe_code)]
+
+// Borrowed views of Java primitive arrays (&[T] / &mut [T] parameters)
+//
+// Elements are pinned (or copied) by `Get<Type>ArrayElements` and released when the guard
+// is dropped, so the generated JNI function releases them on every exit path.
+// `&[T]` is released without copy back, `&mut [T]` writes the changes back to Java.
+
+use jni::objects::{AutoElements, JPrimitiveArray, ReleaseMode, TypeArray};
+
+use crate::prelude::*;
+
+/// Borrow elements of Java primitive array
+pub fn borrow_array_elements<'local, 'other_local, 'array, T: TypeArray>(
+    env: &mut JNIEnv<'local>,
+    array: &'array JPrimitiveArray<'other_local, T>,
+    mutable: bool,
+) -> JResult<AutoElements<'local, 'other_local, 'array, T>> {
+    if array.is_null() {
+        return JException::from_class_and_msg(
+            JExceptionClass::NullPointerException,
+            "Cast failed [null -> &[T]]",
+        )
+        .throw(env);
+    }
+
+    let mode = if mutable {
+        ReleaseMode::CopyBack
+    } else {
+        ReleaseMode::NoCopyBack
+    };
+    // Safety: the array is a live local reference and the elements are released on drop.
+    unsafe { env.get_array_elements(array, mode) }.j_catch_ini(env, "Borrow Java array elements")
+}
+
+/// Java `byte[]` elements as `&[u8]`
+pub fn as_bytes<'a>(elements: &'a AutoElements<'_, '_, '_, jbyte>) -> &'a [u8] {
+    let bytes: &[jbyte] = elements;
+    // Safety: i8 and u8 have the same size and alignment.
+    unsafe { std::slice::from_raw_parts(bytes.as_ptr() as *const u8, bytes.len()) }
+}
+
+/// Java `byte[]` elements as `&mut [u8]`
+pub fn as_bytes_mut<'a>(elements: &'a mut AutoElements<'_, '_, '_, jbyte>) -> &'a mut [u8] {
+    let bytes: &mut [jbyte] = elements;
+    // Safety: i8 and u8 have the same size and alignment.
+    unsafe { std::slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut u8, bytes.len()) }
+}
@@ -0,0 +1,330 @@
+#![allow(unsafe_code)]
+
+// Zero-copy java.nio.ByteBuffer
+//
+// `JDirectBuffer` borrows the memory of a direct `ByteBuffer` passed from Java.
+// `JOwnedBuffer` hands Rust memory to Java as a direct `ByteBuffer`. The memory is freed
+// by the `DirectBufferCleaner` class generated in the Lib class (java.lang.ref.Cleaner)
+// once the buffer is garbage collected.
+
+use std::cell::Cell;
+use std::ops::{Deref, DerefMut};
+use std::sync::Mutex;
+
+use jni::objects::{JByteBuffer, JClass, JObject, JValue, JValueGen, JValueOwned};
+use jni::signature::{JavaType, ReturnType};
+use jni::NativeMethod;
+
+use crate::prelude::*;
+
+/// Java class of direct buffers
+pub const BYTE_BUFFER_CLASS: &str = "java/nio/ByteBuffer";
+
+/// Direct `ByteBuffer` passed from Java (no copy)
+///
+/// Only the bytes between `position()` and `limit()` are visible.
+/// Java must not release or reuse the buffer during the call.
+pub struct JDirectBuffer<'local> {
+    buffer: JByteBuffer<'local>,
+    data: *mut u8,
+    len: usize,
+    read_only: bool,
+}
+
+impl<'local> JDirectBuffer<'local> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// `ByteBuffer.isReadOnly()`
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        if self.len == 0 {
+            return &[];
+        }
+        // Safety: address and capacity are read from a live direct buffer.
+        unsafe { std::slice::from_raw_parts(self.data, self.len) }
+    }
+
+    /// Fails with `UnsupportedOperationException` for read-only buffers
+    pub fn as_mut_slice(&mut self) -> JResult<&mut [u8]> {
+        if self.read_only {
+            return Err(JException::from_class_and_msg(
+                JExceptionClass::UnsupportedOperationException,
+                "ByteBuffer is read-only",
+            ));
+        }
+        if self.len == 0 {
+            return Ok(&mut []);
+        }
+        // Safety: address and capacity are read from a live direct buffer.
+        Ok(unsafe { std::slice::from_raw_parts_mut(self.data, self.len) })
+    }
+
+    /// Java `ByteBuffer` object
+    pub fn into_inner(self) -> JByteBuffer<'local> {
+        self.buffer
+    }
+}
+
+impl<'local> Deref for JDirectBuffer<'local> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<'local> IntoRustType<'local, JDirectBuffer<'local>> for JObject<'local> {
+    fn into_rust(self, env: &mut JNIEnv<'local>) -> JResult<JDirectBuffer<'local>> {
+        if self.is_null() {
+            return JException::from_class_and_msg(
+                JExceptionClass::NullPointerException,
+                "Cast failed [null -> JDirectBuffer]",
+            )
+            .throw(env);
+        }
+
+        let buffer = JByteBuffer::from(self);
+        let address = env.get_direct_buffer_address(&buffer);
+        let capacity = env.get_direct_buffer_capacity(&buffer);
+        let (Ok(address), Ok(_)) = (address, capacity) else {
+            return JException::from_class_and_msg(
+                JExceptionClass::IllegalArgumentException,
+                "ByteBuffer is not direct (use ByteBuffer.allocateDirect)",
+            )
+            .throw(env);
+        };
+
+        // 0 <= position <= limit <= capacity
+        let position: i32 = buffer.call_getter("position", env)?;
+        let limit: i32 = buffer.call_getter("limit", env)?;
+        let read_only: bool = buffer.call_getter("isReadOnly", env)?;
+        // Safety: position is within the buffer capacity.
+        let data = unsafe { address.add(position as usize) };
+        let len = (limit - position) as usize;
+        Ok(JDirectBuffer { buffer, data, len, read_only })
+    }
+}
+
+impl<'local> IntoRustType<'local, JDirectBuffer<'local>> for JValueGen<JObject<'local>> {
+    fn into_rust(self, env: &mut JNIEnv<'local>) -> JResult<JDirectBuffer<'local>> {
+        let obj = self.l().j_catch_ini(env, "Cast failed [JObject -> JDirectBuffer]")?;
+        obj.into_rust(env)
+    }
+}
+
+impl<'local> IntoJavaType<'local, JObject<'local>> for JDirectBuffer<'local> {
+    fn into_java(self, _: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+        Ok(self.buffer.into())
+    }
+}
+
+impl<'local> JTypeInfo<'local> for JDirectBuffer<'local> {
+    fn j_return_type() -> ReturnType {
+        ReturnType::Object
+    }
+
+    fn j_type() -> JavaType {
+        JavaType::Object(BYTE_BUFFER_CLASS.to_string())
+    }
+
+    fn into_j_value(self, env: &mut JNIEnv<'local>) -> JResult<JValueOwned<'local>> {
+        Ok(JValueOwned::Object(self.into_java(env)?))
+    }
+}
+
+/// Rust memory returned to Java as direct `ByteBuffer` (no copy)
+///
+/// Zero-copy needs the `DirectBufferCleaner` class generated by `java-pack` in the Lib class.
+/// Without it the data is copied into `ByteBuffer.allocateDirect`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct JOwnedBuffer(Box<[u8]>);
+
+impl JOwnedBuffer {
+    /// Zeroed buffer
+    pub fn new(len: usize) -> Self {
+        Self(vec![0; len].into_boxed_slice())
+    }
+
+    pub fn into_inner(self) -> Box<[u8]> {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for JOwnedBuffer {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value.into_boxed_slice())
+    }
+}
+
+impl From<Box<[u8]>> for JOwnedBuffer {
+    fn from(value: Box<[u8]>) -> Self {
+        Self(value)
+    }
+}
+
+impl Deref for JOwnedBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for JOwnedBuffer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<'local> IntoRustType<'local, JOwnedBuffer> for JObject<'local> {
+    fn into_rust(self, env: &mut JNIEnv<'local>) -> JResult<JOwnedBuffer> {
+        let buffer: JDirectBuffer = self.into_rust(env)?;
+        Ok(JOwnedBuffer::from(buffer.as_slice().to_vec()))
+    }
+}
+
+impl<'local> IntoRustType<'local, JOwnedBuffer> for JValueGen<JObject<'local>> {
+    fn into_rust(self, env: &mut JNIEnv<'local>) -> JResult<JOwnedBuffer> {
+        let obj = self.l().j_catch_ini(env, "Cast failed [JObject -> JOwnedBuffer]")?;
+        obj.into_rust(env)
+    }
+}
+
+impl<'local> IntoJavaType<'local, JObject<'local>> for JOwnedBuffer {
+    fn into_java(self, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+        let Some(cleaner) = cleaner_class(env) else {
+            return copy_into_direct_buffer(&self, env);
+        };
+
+        let len = self.0.len();
+        let data = Box::into_raw(self.0) as *mut u8;
+        // Safety: memory stays valid until `free_buffer` is called by the cleaner.
+        let buffer = unsafe { env.new_direct_byte_buffer(data, len) };
+        let buffer = match buffer {
+            Ok(buffer) => buffer,
+            Err(err) => {
+                free(data, len);
+                return Err(err).j_catch_ini(env, "Create direct ByteBuffer");
+            }
+        };
+
+        let registered = env
+            .call_static_method(
+                &cleaner,
+                "register",
+                "(Ljava/nio/ByteBuffer;JJ)V",
+                &[(&buffer).into(), JValue::Long(data as i64), JValue::Long(len as i64)],
+            )
+            .j_catch_ini(env, "Register ByteBuffer cleaner");
+        if registered.is_err() {
+            free(data, len);
+        }
+        registered.map(|_| buffer.into())
+    }
+}
+
+impl<'local> JTypeInfo<'local> for JOwnedBuffer {
+    fn j_return_type() -> ReturnType {
+        ReturnType::Object
+    }
+
+    fn j_type() -> JavaType {
+        JavaType::Object(BYTE_BUFFER_CLASS.to_string())
+    }
+
+    fn into_j_value(self, env: &mut JNIEnv<'local>) -> JResult<JValueOwned<'local>> {
+        Ok(JValueOwned::Object(self.into_java(env)?))
+    }
+}
+
+impl JObjectArrayElement for JOwnedBuffer {}
+
+// Cleaner
+
+thread_local! {
+    // DirectBufferCleaner of the crate whose native method is running
+    static CLEANER_CLASS: Cell<Option<&'static str>> = const { Cell::new(None) };
+}
+
+// Cleaner classes with registered native `free` (one Lib class per crate)
+static REGISTERED_CLEANERS: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+/// Use the `DirectBufferCleaner` class on the current thread until the guard is dropped
+///
+/// Entered by generated native methods with the Lib class of their crate.
+pub fn enter_cleaner_class(class_path: &'static str) -> JCleanerClassGuard {
+    let previous = CLEANER_CLASS.with(|class| class.replace(Some(class_path)));
+    JCleanerClassGuard { previous }
+}
+
+/// Restores the previous cleaner class on drop
+#[must_use]
+pub struct JCleanerClassGuard {
+    previous: Option<&'static str>,
+}
+
+impl Drop for JCleanerClassGuard {
+    fn drop(&mut self) {
+        CLEANER_CLASS.with(|class| class.set(self.previous));
+    }
+}
+
+// Cleaner class with registered native `free` (None if not available)
+fn cleaner_class<'local>(env: &mut JNIEnv<'local>) -> Option<JClass<'local>> {
+    let class_path = CLEANER_CLASS.with(|class| class.get())?;
+    let Ok(class) = env.find_class(class_path) else {
+        env.exception_clear().ok();
+        return None;
+    };
+
+    let mut registered = REGISTERED_CLEANERS.lock().ok()?;
+    if !registered.contains(&class_path) {
+        let free = NativeMethod {
+            name: "free".into(),
+            sig: "(JJ)V".into(),
+            fn_ptr: free_buffer as *mut std::ffi::c_void,
+        };
+        if env.register_native_methods(&class, &[free]).is_err() {
+            env.exception_clear().ok();
+            return None;
+        }
+        registered.push(class_path);
+    }
+    Some(class)
+}
+
+// DirectBufferCleaner.free(long address, long capacity)
+extern "system" fn free_buffer(_: JNIEnv, _: JClass, address: jlong, capacity: jlong) {
+    free(address as *mut u8, capacity as usize);
+}
+
+fn free(data: *mut u8, len: usize) {
+    // Safety: pointer and length come from `Box::<[u8]>::into_raw` in `JOwnedBuffer::into_java`.
+    drop(unsafe { Box::from_raw(std::ptr::slice_from_raw_parts_mut(data, len)) });
+}
+
+// Fallback without cleaner class (Lib class not generated by java-pack): copy into ByteBuffer.allocateDirect
+fn copy_into_direct_buffer<'local>(data: &[u8], env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+    let buffer = env
+        .call_static_method(
+            BYTE_BUFFER_CLASS,
+            "allocateDirect",
+            "(I)Ljava/nio/ByteBuffer;",
+            &[JValue::Int(data.len() as i32)],
+        )
+        .j_catch_ini(env, "Call Java method: ByteBuffer.allocateDirect()")?
+        .l()
+        .j_catch_ini(env, "Call Java method: ByteBuffer.allocateDirect()")?;
+    let mut direct: JDirectBuffer = buffer.into_rust(env)?;
+    direct.as_mut_slice()?.copy_from_slice(data);
+    Ok(direct.into_inner().into())
+}
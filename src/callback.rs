@@ -0,0 +1,231 @@
+#![allow(unsafe_code)]
+
+// Java functional interfaces as Rust callbacks
+//
+// `JCallback<Args, Ret>` wraps a Java lambda passed as a parameter. `Args` is a tuple of
+// up to two arguments and `Ret = ()` selects the void interface:
+//
+// JCallback<(), ()>       -> Runnable
+// JCallback<(), R>        -> Supplier<R>
+// JCallback<(A,), ()>     -> Consumer<A>
+// JCallback<(A,), R>      -> Function<A, R>
+// JCallback<(A, B), ()>   -> BiConsumer<A, B>
+// JCallback<(A, B), R>    -> BiFunction<A, B, R>
+//
+// Arguments are converted with `JTypeInfo::into_j_value` (primitives are boxed),
+// the result with `IntoRustType`. An exception thrown by the callback is cleared and
+// returned as `JException` (`JavaThrowable`: class, message and stack trace).
+
+use std::marker::PhantomData;
+
+use jni::objects::{GlobalRef, JObject, JValue, JValueGen, JValueOwned};
+use jni::signature::{JavaType, Primitive, ReturnType};
+
+use crate::exception::j_take_exception;
+use crate::java_types::call_static_object;
+use crate::prelude::*;
+
+/// Java functional interface passed from Java
+pub struct JCallback<Args, Ret = ()> {
+    callback: GlobalRef,
+    _signature: PhantomData<fn(Args) -> Ret>,
+}
+
+/// Callback arguments: `()`, `(A,)`, `(A, B)`
+pub trait JCallbackArgs {
+    const ARITY: usize;
+}
+
+impl JCallbackArgs for () {
+    const ARITY: usize = 0;
+}
+
+impl<A> JCallbackArgs for (A,) {
+    const ARITY: usize = 1;
+}
+
+impl<A, B> JCallbackArgs for (A, B) {
+    const ARITY: usize = 2;
+}
+
+// (Class, Method) by arity and void return
+fn functional_interface(arity: usize, void: bool) -> (&'static str, &'static str) {
+    match (arity, void) {
+        (0, true) => ("java/lang/Runnable", "run"),
+        (0, false) => ("java/util/function/Supplier", "get"),
+        (1, true) => ("java/util/function/Consumer", "accept"),
+        (1, false) => ("java/util/function/Function", "apply"),
+        (2, true) => ("java/util/function/BiConsumer", "accept"),
+        _ => ("java/util/function/BiFunction", "apply"),
+    }
+}
+
+fn is_void<'local, Ret: JTypeInfo<'local>>() -> bool {
+    matches!(Ret::j_return_type(), ReturnType::Primitive(Primitive::Void))
+}
+
+impl<Args: JCallbackArgs, Ret> JCallback<Args, Ret> {
+    /// Java object (lambda)
+    pub fn as_obj(&self) -> &JObject<'static> {
+        self.callback.as_obj()
+    }
+
+    fn invoke<'local, F>(&self, args: F, env: &mut JNIEnv<'local>) -> JResult<Ret>
+    where
+        F: FnOnce(&mut JNIEnv<'local>) -> JResult<Vec<JValueOwned<'local>>>,
+        Ret: JTypeInfo<'local>,
+        JObject<'local>: IntoRustType<'local, Ret>,
+    {
+        // Boxed arguments and the result are released with the frame
+        // (callbacks are called in loops)
+        env.push_local_frame(LOCAL_FRAME_CAPACITY)
+            .j_catch_ini(env, "Failed to create local frame")?;
+        let result = self.invoke_in_frame(args, env);
+        let primitive = matches!(Ret::j_type(), JavaType::Primitive(_));
+        match result {
+            Ok(obj) if primitive => {
+                let value = obj.into_rust(env);
+                pop_local_frame(&JObject::null(), env);
+                value
+            }
+            Ok(obj) => pop_local_frame(&obj, env).into_rust(env),
+            Err(err) => {
+                pop_local_frame(&JObject::null(), env);
+                Err(err)
+            }
+        }
+    }
+
+    fn invoke_in_frame<'local, F>(
+        &self,
+        args: F,
+        env: &mut JNIEnv<'local>,
+    ) -> JResult<JObject<'local>>
+    where
+        F: FnOnce(&mut JNIEnv<'local>) -> JResult<Vec<JValueOwned<'local>>>,
+        Ret: JTypeInfo<'local>,
+    {
+        let void = is_void::<Ret>();
+        let (class, method) = functional_interface(Args::ARITY, void);
+        let ret = if void { "V" } else { "Ljava/lang/Object;" };
+
+        let mut objects = vec![];
+        for arg in args(env)? {
+            objects.push(box_value(arg, env)?);
+        }
+        let sig = format!("({}){ret}", "Ljava/lang/Object;".repeat(objects.len()));
+        let values: Vec<JValue> = objects.iter().map(JValue::from).collect();
+
+        match env.call_method(self.as_obj(), method, &sig, &values) {
+            Ok(JValueGen::Object(obj)) => Ok(obj),
+            Ok(_) => Ok(JObject::null()),
+            Err(jni::errors::Error::JavaException) => Err(j_take_exception(env)),
+            Err(err) => {
+                let msg = format!("Call Java callback: {class}.{method}()");
+                Err(err).j_catch_ini(env, &msg)
+            }
+        }
+    }
+}
+
+macro_rules! callback_call {
+    ($($arg:ident: $ty:ident),*) => {
+        impl<$($ty,)* Ret> JCallback<($($ty,)*), Ret> {
+            /// Call Java callback
+            pub fn call<'local>(&self, env: &mut JNIEnv<'local>, $($arg: $ty),*) -> JResult<Ret>
+            where
+                $($ty: JTypeInfo<'local>,)*
+                Ret: JTypeInfo<'local>,
+                JObject<'local>: IntoRustType<'local, Ret>,
+            {
+                #[allow(unused_variables)]
+                self.invoke(|env| Ok(vec![$($arg.into_j_value(env)?),*]), env)
+            }
+        }
+    };
+}
+
+callback_call!();
+callback_call!(a: A);
+callback_call!(a: A, b: B);
+
+pub(crate) const LOCAL_FRAME_CAPACITY: i32 = 8;
+
+// Pop the callback frame, `result` is moved to the outer frame
+pub(crate) fn pop_local_frame<'local>(
+    result: &JObject,
+    env: &mut JNIEnv<'local>,
+) -> JObject<'local> {
+    // Safety: references created in the frame are not used after this call
+    // (except the returned one).
+    unsafe { env.pop_local_frame(result) }.unwrap_or_default()
+}
+
+// Box primitive values (Integer.valueOf, ...)
+fn box_value<'local>(
+    value: JValueOwned<'local>,
+    env: &mut JNIEnv<'local>,
+) -> JResult<JObject<'local>> {
+    let (class, sig) = match value {
+        JValueGen::Object(obj) => return Ok(obj),
+        JValueGen::Void => return Ok(JObject::null()),
+        JValueGen::Byte(_) => ("java/lang/Byte", "(B)Ljava/lang/Byte;"),
+        JValueGen::Char(_) => ("java/lang/Character", "(C)Ljava/lang/Character;"),
+        JValueGen::Short(_) => ("java/lang/Short", "(S)Ljava/lang/Short;"),
+        JValueGen::Int(_) => ("java/lang/Integer", "(I)Ljava/lang/Integer;"),
+        JValueGen::Long(_) => ("java/lang/Long", "(J)Ljava/lang/Long;"),
+        JValueGen::Bool(_) => ("java/lang/Boolean", "(Z)Ljava/lang/Boolean;"),
+        JValueGen::Float(_) => ("java/lang/Float", "(F)Ljava/lang/Float;"),
+        JValueGen::Double(_) => ("java/lang/Double", "(D)Ljava/lang/Double;"),
+    };
+    call_static_object(class, "valueOf", sig, &[value.borrow()], env)
+}
+
+impl<'local, Args, Ret> IntoRustType<'local, JCallback<Args, Ret>> for JObject<'local> {
+    fn into_rust(self, env: &mut JNIEnv<'local>) -> JResult<JCallback<Args, Ret>> {
+        if self.is_null() {
+            return JException::from_class_and_msg(
+                JExceptionClass::NullPointerException,
+                "Cast failed [null -> JCallback]",
+            )
+            .throw(env);
+        }
+        let callback = env.new_global_ref(self).j_catch_ini(env, "Failed to create GlobalRef")?;
+        Ok(JCallback {
+            callback,
+            _signature: PhantomData,
+        })
+    }
+}
+
+impl<'local, Args, Ret> IntoRustType<'local, JCallback<Args, Ret>> for JValueGen<JObject<'local>> {
+    fn into_rust(self, env: &mut JNIEnv<'local>) -> JResult<JCallback<Args, Ret>> {
+        let obj = self.l().j_catch_ini(env, "Cast failed [JObject -> JCallback]")?;
+        obj.into_rust(env)
+    }
+}
+
+impl<'local, Args, Ret> IntoJavaType<'local, JObject<'local>> for JCallback<Args, Ret> {
+    fn into_java(self, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+        env.new_local_ref(self.callback.as_obj()).j_catch_ini(env, "Failed to create JObject")
+    }
+}
+
+impl<'local, Args, Ret> JTypeInfo<'local> for JCallback<Args, Ret>
+where
+    Args: JCallbackArgs,
+    Ret: JTypeInfo<'local>,
+{
+    fn j_return_type() -> ReturnType {
+        ReturnType::Object
+    }
+
+    fn j_type() -> JavaType {
+        let (class, _) = functional_interface(Args::ARITY, is_void::<Ret>());
+        JavaType::Object(class.to_string())
+    }
+
+    fn into_j_value(self, env: &mut JNIEnv<'local>) -> JResult<JValueOwned<'local>> {
+        Ok(JValueOwned::Object(self.into_java(env)?))
+    }
+}
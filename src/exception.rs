@@ -1,4 +1,13 @@
-use std::{backtrace::Backtrace, fmt::Debug, rc::Rc};
+use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    cell::{Cell, RefCell},
+    fmt::Debug,
+    panic::AssertUnwindSafe,
+    rc::Rc,
+    sync::{Once, OnceLock},
+};
+
+use jni::objects::{GlobalRef, JObject, JObjectArray, JString, JThrowable};
 
 use crate::prelude::IntoJavaType;
 
@@ -18,7 +27,7 @@ pub enum JExceptionClass {
     NullPointerException,
     NumberFormatException,
     SecurityException,
-    StringIndexOutOfBounds,
+    StringIndexOutOfBoundsException,
     UnsupportedOperationException,
     ClassNotFoundException,
     CloneNotSupportedException,
@@ -27,11 +36,118 @@ pub enum JExceptionClass {
     InterruptedException,
     NoSuchFieldException,
     NoSuchMethodException,
+    // java.io
+    IOException,
+    UncheckedIOException,
+    UnsupportedEncodingException,
+    FileNotFoundException,
+    EOFException,
+    InterruptedIOException,
+    // java.net
+    SocketTimeoutException,
+    // java.nio.file
+    NoSuchFileException,
+    AccessDeniedException,
+    FileAlreadyExistsException,
+    // java.util.concurrent
+    TimeoutException,
+    // Rust panic (RustPanicException generated in the lib package)
+    RustPanicException,
+    // Any Java exception class (com/acme/ImageError)
+    Custom(String),
 }
 
 impl JExceptionClass {
     pub fn get_class_path(&self) -> String {
-        format!("java/lang/{:?}", self)
+        let class_path = match self {
+            JExceptionClass::IOException => "java/io/IOException",
+            JExceptionClass::UncheckedIOException => "java/io/UncheckedIOException",
+            JExceptionClass::UnsupportedEncodingException => "java/io/UnsupportedEncodingException",
+            JExceptionClass::FileNotFoundException => "java/io/FileNotFoundException",
+            JExceptionClass::EOFException => "java/io/EOFException",
+            JExceptionClass::InterruptedIOException => "java/io/InterruptedIOException",
+            JExceptionClass::SocketTimeoutException => "java/net/SocketTimeoutException",
+            JExceptionClass::NoSuchFileException => "java/nio/file/NoSuchFileException",
+            JExceptionClass::AccessDeniedException => "java/nio/file/AccessDeniedException",
+            JExceptionClass::FileAlreadyExistsException => "java/nio/file/FileAlreadyExistsException",
+            JExceptionClass::TimeoutException => "java/util/concurrent/TimeoutException",
+            JExceptionClass::RustPanicException => PANIC_CLASS.get().unwrap_or(&"java/lang/RuntimeException"),
+            JExceptionClass::Custom(class_path) => return class_path.replace('.', "/"),
+            _ => return format!("java/lang/{:?}", self),
+        };
+        class_path.to_string()
+    }
+
+    // Exception class of Rust error (std::io::Error by ErrorKind)
+    pub fn from_error(error: &(dyn std::error::Error + 'static)) -> Self {
+        match error.downcast_ref::<std::io::Error>() {
+            Some(error) => JExceptionClass::from(error.kind()),
+            None => JExceptionClass::RuntimeException,
+        }
+    }
+
+    // Match Java class name (java.lang.IllegalStateException)
+    pub fn from_class_name(name: &str) -> Option<Self> {
+        let class = match name {
+            "java.io.IOException" => JExceptionClass::IOException,
+            "java.io.UncheckedIOException" => JExceptionClass::UncheckedIOException,
+            "java.io.UnsupportedEncodingException" => JExceptionClass::UnsupportedEncodingException,
+            "java.io.FileNotFoundException" => JExceptionClass::FileNotFoundException,
+            "java.io.EOFException" => JExceptionClass::EOFException,
+            "java.io.InterruptedIOException" => JExceptionClass::InterruptedIOException,
+            "java.net.SocketTimeoutException" => JExceptionClass::SocketTimeoutException,
+            "java.nio.file.NoSuchFileException" => JExceptionClass::NoSuchFileException,
+            "java.nio.file.AccessDeniedException" => JExceptionClass::AccessDeniedException,
+            "java.nio.file.FileAlreadyExistsException" => JExceptionClass::FileAlreadyExistsException,
+            "java.util.concurrent.TimeoutException" => JExceptionClass::TimeoutException,
+            _ => Self::from_java_lang_class_name(name)?,
+        };
+        Some(class)
+    }
+
+    fn from_java_lang_class_name(name: &str) -> Option<Self> {
+        let class = match name.strip_prefix("java.lang.")? {
+            "RuntimeException" => JExceptionClass::RuntimeException,
+            "ArithmeticException" => JExceptionClass::ArithmeticException,
+            "ArrayIndexOutOfBoundsException" => JExceptionClass::ArrayIndexOutOfBoundsException,
+            "ArrayStoreException" => JExceptionClass::ArrayStoreException,
+            "ClassCastException" => JExceptionClass::ClassCastException,
+            "IllegalArgumentException" => JExceptionClass::IllegalArgumentException,
+            "IllegalMonitorStateException" => JExceptionClass::IllegalMonitorStateException,
+            "IllegalStateException" => JExceptionClass::IllegalStateException,
+            "IllegalThreadStateException" => JExceptionClass::IllegalThreadStateException,
+            "IndexOutOfBoundsException" => JExceptionClass::IndexOutOfBoundsException,
+            "NegativeArraySizeException" => JExceptionClass::NegativeArraySizeException,
+            "NullPointerException" => JExceptionClass::NullPointerException,
+            "NumberFormatException" => JExceptionClass::NumberFormatException,
+            "SecurityException" => JExceptionClass::SecurityException,
+            "StringIndexOutOfBoundsException" => JExceptionClass::StringIndexOutOfBoundsException,
+            "UnsupportedOperationException" => JExceptionClass::UnsupportedOperationException,
+            "ClassNotFoundException" => JExceptionClass::ClassNotFoundException,
+            "CloneNotSupportedException" => JExceptionClass::CloneNotSupportedException,
+            "IllegalAccessException" => JExceptionClass::IllegalAccessException,
+            "InstantiationException" => JExceptionClass::InstantiationException,
+            "InterruptedException" => JExceptionClass::InterruptedException,
+            "NoSuchFieldException" => JExceptionClass::NoSuchFieldException,
+            "NoSuchMethodException" => JExceptionClass::NoSuchMethodException,
+            _ => return None,
+        };
+        Some(class)
+    }
+}
+
+// Convert std::io::ErrorKind to java.io / java.net / java.nio.file exception (class)
+impl From<std::io::ErrorKind> for JExceptionClass {
+    fn from(value: std::io::ErrorKind) -> Self {
+        match value {
+            std::io::ErrorKind::NotFound => JExceptionClass::FileNotFoundException,
+            std::io::ErrorKind::PermissionDenied => JExceptionClass::AccessDeniedException,
+            std::io::ErrorKind::AlreadyExists => JExceptionClass::FileAlreadyExistsException,
+            std::io::ErrorKind::UnexpectedEof => JExceptionClass::EOFException,
+            std::io::ErrorKind::TimedOut => JExceptionClass::SocketTimeoutException,
+            std::io::ErrorKind::Interrupted => JExceptionClass::InterruptedIOException,
+            _ => JExceptionClass::IOException,
+        }
     }
 }
 
@@ -64,7 +180,10 @@ impl From<&jni::errors::Error> for JExceptionClass {
 
 impl std::fmt::Display for JExceptionClass {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            JExceptionClass::Custom(class_path) => write!(f, "{}", class_path.replace('/', ".")),
+            _ => write!(f, "{:?}", self),
+        }
     }
 }
 
@@ -90,7 +209,7 @@ impl JException {
 
     pub fn from_std<E: std::error::Error + 'static>(error: E) -> Self {
         Self {
-            class: JExceptionClass::RuntimeException,
+            class: JExceptionClass::from_error(&error),
             error: Rc::new(error),
         }
     }
@@ -104,6 +223,17 @@ impl JException {
             error: Rc::new(error),
         }
     }
+
+    /// Java exception captured by `j_take_exception`
+    pub fn java_throwable(&self) -> Option<&JavaThrowable> {
+        self.error.downcast_ref::<JavaThrowable>()
+    }
+
+    // Throw as Java exception and return as error
+    pub fn throw<T>(self, env: &mut jni::JNIEnv<'_>) -> JResult<T> {
+        env.j_throw_exception(self.clone());
+        Err(self)
+    }
 }
 
 impl jni::errors::ToException for JException {
@@ -138,12 +268,40 @@ impl std::fmt::Debug for JException {
 
 pub type JResult<T, E = JException> = core::result::Result<T, E>;
 
-pub fn j_result_handler<'a, T, R: Default>(
-    result: JResult<T, JException>,
+/// Rust error thrown as generated Java exception class (#[derive(JavaException)])
+pub trait JavaException: std::error::Error + Sized + 'static {
+    /// Java class path (com/acme/ImageError$Decode)
+    fn java_class(&self) -> String;
+
+    fn into_exception(self) -> JException {
+        let class = JExceptionClass::Custom(self.java_class());
+        JException::from_std_with_class(self, class)
+    }
+}
+
+/// Error returned by native method: JResult<T> or JResult<T, E: JavaException>
+pub trait IntoJException {
+    fn into_j_exception(self) -> JException;
+}
+
+impl IntoJException for JException {
+    fn into_j_exception(self) -> JException {
+        self
+    }
+}
+
+impl<E: JavaException> IntoJException for E {
+    fn into_j_exception(self) -> JException {
+        self.into_exception()
+    }
+}
+
+pub fn j_result_handler<'a, T, R: Default, E: IntoJException>(
+    result: JResult<T, E>,
     env: &mut jni::JNIEnv<'a>,
 ) -> R
 where
-    T: IntoJavaType<'a, R> + Default,
+    T: IntoJavaType<'a, R>,
 {
     match result {
         Ok(ok) => match ok.into_java(env) {
@@ -154,7 +312,7 @@ where
             }
         },
         Err(err) => {
-            env.j_throw_exception(err);
+            env.j_throw_exception(err.into_j_exception());
             Default::default()
         }
     }
@@ -165,7 +323,7 @@ pub fn option_handler<'a, T, R: Default>(
     env: &mut jni::JNIEnv<'a>,
 ) -> R
 where
-    T: IntoJavaType<'a, R> + Default,
+    T: IntoJavaType<'a, R>,
 {
     match result {
         Some(ok) => match ok.into_java(env) {
@@ -179,20 +337,394 @@ where
     }
 }
 
+/// Java exception caught in Rust: class, `getMessage()` and stack trace (can be rethrown unchanged)
+#[derive(Debug, Clone)]
+pub struct JavaThrowable {
+    /// Java class name (java.lang.IllegalStateException)
+    pub class_name: String,
+    pub message: Option<String>,
+    /// `StackTraceElement.toString()` (com.acme.Repo.save(Repo.java:12))
+    pub stack_trace: Vec<String>,
+    throwable: Option<GlobalRef>,
+}
+
+impl JavaThrowable {
+    /// The original Java exception object
+    pub fn throwable(&self) -> Option<&GlobalRef> {
+        self.throwable.as_ref()
+    }
+}
+
+impl std::fmt::Display for JavaThrowable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.message {
+            Some(message) => write!(f, "{message}"),
+            None => write!(f, "{}", self.class_name),
+        }
+    }
+}
+
+impl std::error::Error for JavaThrowable {}
+
+/// Clear the pending Java exception and capture it as `JException` (error: `JavaThrowable`)
+pub fn j_take_exception(env: &mut jni::JNIEnv<'_>) -> JException {
+    let throwable = match env.exception_occurred() {
+        Ok(throwable) if !throwable.is_null() => throwable,
+        _ => return JException::from_class_and_msg(JExceptionClass::RuntimeException, "Java exception"),
+    };
+    env.exception_clear().ok();
+
+    let class_name = env
+        .get_object_class(&throwable)
+        .ok()
+        .and_then(|class| crate::java_types::call_string(&class, "getName", env).ok())
+        .unwrap_or_else(|| "java.lang.Throwable".to_string());
+    let message: Option<String> = env
+        .call_method(&throwable, "getMessage", "()Ljava/lang/String;", &[])
+        .ok()
+        .and_then(|msg| crate::j2r::IntoRustType::into_rust(msg, env).ok())
+        .flatten();
+    let stack_trace = j_stack_trace(&throwable, env).unwrap_or_default();
+    let throwable = env.new_global_ref(&throwable).ok();
+    // the exception was cleared, a failed lookup must not leave a new one pending
+    env.exception_clear().ok();
+
+    let class = JExceptionClass::from_class_name(&class_name)
+        .unwrap_or_else(|| JExceptionClass::Custom(class_name.clone()));
+    let error = JavaThrowable {
+        class_name,
+        message,
+        stack_trace,
+        throwable,
+    };
+    JException::from_std_with_class(error, class)
+}
+
+fn j_stack_trace(throwable: &JThrowable<'_>, env: &mut jni::JNIEnv<'_>) -> JResult<Vec<String>> {
+    // own frame: the exception may be captured deep in a native call
+    env.with_local_frame(crate::callback::LOCAL_FRAME_CAPACITY, |env| {
+        let trace = env
+            .call_method(throwable, "getStackTrace", "()[Ljava/lang/StackTraceElement;", &[])?
+            .l()?;
+        let trace = JObjectArray::from(trace);
+        let len = env.get_array_length(&trace)?;
+
+        let mut stack_trace = Vec::with_capacity(len as usize);
+        for index in 0..len {
+            let element = env.get_object_array_element(&trace, index)?;
+            let line = JString::from(env.call_method(&element, "toString", "()Ljava/lang/String;", &[])?.l()?);
+            stack_trace.push(env.get_string_owned(&line)?);
+            env.delete_local_ref(line)?;
+            env.delete_local_ref(element)?;
+        }
+        Ok(stack_trace)
+    })
+}
+
+// Rust panic
+
+static PANIC_CLASS: OnceLock<&'static str> = OnceLock::new();
+static PANIC_HOOK: Once = Once::new();
+
+thread_local! {
+    // Set while j_catch_panic runs (other panics are left to the default hook)
+    static CATCHING_PANIC: Cell<bool> = const { Cell::new(false) };
+    // Location and backtrace of the last panic (set by the panic hook)
+    static LAST_PANIC: RefCell<Option<(String, Backtrace)>> = const { RefCell::new(None) };
+}
+
+/// Set `RustPanicException` class path (called by `j_panic_handler` on the first panic)
+pub fn set_panic_class(class_path: &'static str) {
+    PANIC_CLASS.get_or_init(|| class_path);
+}
+
+/// Rust panic caught at the JNI boundary
+#[derive(Debug)]
+pub struct RustPanic {
+    pub message: String,
+    pub location: Option<String>,
+    pub backtrace: Option<Backtrace>,
+}
+
+impl std::fmt::Display for RustPanic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "Rust panic: {} (at {location})", self.message),
+            None => write!(f, "Rust panic: {}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for RustPanic {}
+
+/// Run JNI function body, a panic is returned as `RustPanic` (must not unwind into the JVM)
+pub fn j_catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, RustPanic> {
+    PANIC_HOOK.call_once(|| {
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if CATCHING_PANIC.try_with(Cell::get).unwrap_or_default() {
+                let location = info.location().map(|l| l.to_string()).unwrap_or_default();
+                LAST_PANIC.with(|last| *last.borrow_mut() = Some((location, Backtrace::force_capture())));
+            }
+            default_hook(info);
+        }));
+    });
+
+    let catching = CATCHING_PANIC.with(|c| c.replace(true));
+    let result = std::panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING_PANIC.with(|c| c.set(catching));
+    result.map_err(|payload| {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&'static str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "Box<dyn Any>".to_string(),
+            },
+        };
+        let (location, backtrace) = LAST_PANIC.with(|last| last.borrow_mut().take()).unzip();
+        RustPanic { message, location, backtrace }
+    })
+}
+
+/// Throw caught panic as `RustPanicException` (`panic_class`: class path generated in the lib package)
+pub fn j_panic_handler<R: Default>(result: Result<R, RustPanic>, panic_class: &'static str, env: &mut jni::JNIEnv<'_>) -> R {
+    match result {
+        Ok(ok) => ok,
+        Err(panic) => {
+            set_panic_class(panic_class);
+            env.j_throw_exception(JException::from_std_with_class(panic, JExceptionClass::RustPanicException));
+            Default::default()
+        }
+    }
+}
+
 // JNIEnv Util
 
+// Rust frames are captured with RUST_BACKTRACE=1 (or RUST_LIB_BACKTRACE=1) and resolved only when thrown
 macro_rules! jthrow {
     ( $env:expr => $j_class:expr , $message:tt) => {
+        jthrow!($env => $j_class, $message, None, &Backtrace::capture());
+    };
+    ( $env:expr => $j_class:expr , $message:tt, $source:expr, $backtrace:expr) => {
         let error = $env.exception_occurred().unwrap_or_default();
         if error.is_null() {
-            let message = format!(
-                "\nRust Error:  {}\nRust Backtrace:\n{}\n",
-                &$message,
-                Backtrace::force_capture()
-            );
-            $env.throw_new(($j_class).get_class_path(), &message).ok();
+            j_throw_new($env, &$j_class, &$message.to_string(), $source, $backtrace);
+        }
+    };
+}
+
+// Throw new exception: error sources as Java causes, Rust frames on top of the Java stack trace
+fn j_throw_new(
+    env: &mut jni::JNIEnv<'_>,
+    j_class: &JExceptionClass,
+    message: &str,
+    source: Option<&(dyn std::error::Error + 'static)>,
+    backtrace: &Backtrace,
+) {
+    let class_path = j_class.get_class_path();
+    match j_new_exception(env, &class_path, message, source) {
+        Ok(exception) => {
+            j_set_rust_stack_trace(env, &exception, backtrace).ok();
+            env.exception_clear().ok();
+            env.throw(exception).ok();
+        }
+        // e.g. NoClassDefFoundError is already pending
+        Err(_) if env.exception_check().unwrap_or_default() => {}
+        Err(_) => {
+            env.throw_new(&class_path, message).ok();
+        }
+    }
+}
+
+// Java exception with (String message, Throwable cause) for each source() level
+fn j_new_exception<'local>(
+    env: &mut jni::JNIEnv<'local>,
+    class_path: &str,
+    message: &str,
+    source: Option<&(dyn std::error::Error + 'static)>,
+) -> jni::errors::Result<JThrowable<'local>> {
+    let java_cause = source
+        .and_then(|source| source.downcast_ref::<JavaThrowable>())
+        .and_then(JavaThrowable::throwable);
+    let cause = match (java_cause, source) {
+        // captured Java exception is the cause itself
+        (Some(throwable), _) => Some(JThrowable::from(env.new_local_ref(throwable)?)),
+        (None, Some(source)) => {
+            let source_class = JExceptionClass::from_error(source).get_class_path();
+            Some(j_new_exception(env, &source_class, &source.to_string(), source.source())?)
+        }
+        (None, None) => None,
+    };
+    let j_message = env.new_string(message)?;
+
+    // UncheckedIOException has no (String) constructor, the cause must be IOException
+    if class_path == "java/io/UncheckedIOException" {
+        let cause = match cause {
+            Some(cause) if env.is_instance_of(&cause, "java/io/IOException")? => cause,
+            cause => j_new_exception_with_cause(env, "java/io/IOException", &j_message, cause)?,
+        };
+        let exception = env.new_object(
+            class_path,
+            "(Ljava/lang/String;Ljava/io/IOException;)V",
+            &[(&j_message).into(), (&cause).into()],
+        )?;
+        return Ok(JThrowable::from(exception));
+    }
+    j_new_exception_with_cause(env, class_path, &j_message, cause)
+}
+
+fn j_new_exception_with_cause<'local>(
+    env: &mut jni::JNIEnv<'local>,
+    class_path: &str,
+    message: &JString<'_>,
+    cause: Option<JThrowable<'_>>,
+) -> jni::errors::Result<JThrowable<'local>> {
+    let Some(cause) = cause else {
+        let exception = env.new_object(class_path, "(Ljava/lang/String;)V", &[message.into()])?;
+        return Ok(JThrowable::from(exception));
+    };
+
+    let exception = env.new_object(
+        class_path,
+        "(Ljava/lang/String;Ljava/lang/Throwable;)V",
+        &[message.into(), (&cause).into()],
+    );
+    let exception = match exception {
+        Ok(exception) => exception,
+        // no (String, Throwable) constructor (FileNotFoundException, NumberFormatException)
+        Err(jni::errors::Error::MethodNotFound { .. }) | Err(jni::errors::Error::JavaException) => {
+            env.exception_clear()?;
+            let exception = env.new_object(class_path, "(Ljava/lang/String;)V", &[message.into()])?;
+            env.call_method(
+                &exception,
+                "initCause",
+                "(Ljava/lang/Throwable;)Ljava/lang/Throwable;",
+                &[(&cause).into()],
+            )?;
+            exception
         }
+        Err(err) => return Err(err),
     };
+    Ok(JThrowable::from(exception))
+}
+
+// Rust backtrace frames as StackTraceElement (before the Java frames)
+fn j_set_rust_stack_trace(
+    env: &mut jni::JNIEnv<'_>,
+    exception: &JThrowable<'_>,
+    backtrace: &Backtrace,
+) -> jni::errors::Result<()> {
+    if backtrace.status() != BacktraceStatus::Captured {
+        return Ok(());
+    }
+    let frames = rust_frames(&backtrace.to_string());
+    if frames.is_empty() {
+        return Ok(());
+    }
+
+    let element_class = "java/lang/StackTraceElement";
+    let java_trace = env
+        .call_method(exception, "getStackTrace", "()[Ljava/lang/StackTraceElement;", &[])?
+        .l()?;
+    let java_trace = JObjectArray::from(java_trace);
+    let java_len = env.get_array_length(&java_trace)?;
+
+    let trace = env.new_object_array(frames.len() as i32 + java_len, element_class, JObject::null())?;
+    for (index, frame) in frames.iter().enumerate() {
+        let declaring_class = env.new_string(&frame.module)?;
+        let method_name = env.new_string(&frame.function)?;
+        let file_name = match &frame.file {
+            Some(file) => JObject::from(env.new_string(file)?),
+            None => JObject::null(),
+        };
+        let element = env.new_object(
+            element_class,
+            "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;I)V",
+            &[
+                (&declaring_class).into(),
+                (&method_name).into(),
+                (&file_name).into(),
+                frame.line.into(),
+            ],
+        )?;
+        env.set_object_array_element(&trace, index as i32, &element)?;
+        env.delete_local_ref(element)?;
+        env.delete_local_ref(declaring_class)?;
+        env.delete_local_ref(method_name)?;
+        env.delete_local_ref(file_name)?;
+    }
+    for index in 0..java_len {
+        let element = env.get_object_array_element(&java_trace, index)?;
+        env.set_object_array_element(&trace, frames.len() as i32 + index, &element)?;
+        env.delete_local_ref(element)?;
+    }
+
+    env.call_method(
+        exception,
+        "setStackTrace",
+        "([Ljava/lang/StackTraceElement;)V",
+        &[(&trace).into()],
+    )?;
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+struct RustFrame {
+    module: String,
+    function: String,
+    file: Option<String>,
+    line: i32,
+}
+
+// Parse backtrace:
+//   3: test_macro::check_image
+//             at ./src/lib.rs:10:5
+fn rust_frames(backtrace: &str) -> Vec<RustFrame> {
+    let mut frames: Vec<RustFrame> = vec![];
+    for line in backtrace.lines().map(str::trim) {
+        if let Some(location) = line.strip_prefix("at ") {
+            let mut parts = location.rsplitn(3, ':');
+            let (Some(_column), Some(line), Some(file)) = (parts.next(), parts.next(), parts.next()) else {
+                continue;
+            };
+            if let Some(frame) = frames.last_mut().filter(|f| f.file.is_none()) {
+                frame.file = Some(file.to_string());
+                frame.line = line.parse().unwrap_or(-1);
+            }
+            continue;
+        }
+
+        let Some((index, symbol)) = line.split_once(": ") else {
+            continue;
+        };
+        if index.parse::<usize>().is_err() {
+            continue;
+        }
+        let (module, function) = symbol.rsplit_once("::").unwrap_or(("rust", symbol));
+        frames.push(RustFrame {
+            module: module.to_string(),
+            function: function.to_string(),
+            file: None,
+            line: -1,
+        });
+    }
+
+    // skip std / backtrace capture / panic machinery, stop at the JNI entry (Java_com_acme_Lib_method)
+    let is_internal = |f: &RustFrame| {
+        let symbol = format!("{}::{}", f.module, f.function);
+        ["std::", "core::", "alloc::", "<std::", "<core::", "<alloc::", "__rust", "rust_begin_unwind"]
+            .iter()
+            .any(|prefix| symbol.starts_with(prefix) || f.function.starts_with(prefix))
+            || (f.module.starts_with("java_bindgen::exception") && !f.module.contains("::tests"))
+            || symbol.contains(" as java_bindgen::exception::")
+    };
+    let end = frames
+        .iter()
+        .position(|f| f.function.starts_with("Java_"))
+        .map_or(frames.len(), |i| i + 1);
+    frames.truncate(end);
+    frames.retain(|f| !is_internal(f));
+    frames
 }
 
 #[allow(non_snake_case)]
@@ -213,15 +745,31 @@ impl<'local> JNIEnvUtils for jni::JNIEnv<'local> {
         jthrow!( self => &JExceptionClass::RuntimeException, message);
     }
     fn j_throw_cause(&mut self, j_class: JExceptionClass, cause: &impl std::error::Error) {
-        jthrow!( self => j_class, cause);
+        jthrow!( self => j_class, cause, cause.source(), &Backtrace::capture());
     }
     fn j_throw(&mut self, j_class: JExceptionClass) {
         jthrow!( self => j_class, j_class);
     }
     fn j_throw_exception(&mut self, ex: JException) {
+        // captured Java exception is rethrown unchanged
+        if let Some(throwable) = ex.java_throwable().and_then(JavaThrowable::throwable) {
+            if !self.exception_check().unwrap_or_default() {
+                self.throw(<&JThrowable>::from(throwable.as_obj())).ok();
+            }
+            return;
+        }
+
         let c = ex.class;
         let e = ex.error;
-        jthrow!( self => c, e);
+        // panic backtrace (not the handler's)
+        match e.downcast_ref::<RustPanic>() {
+            Some(RustPanic { backtrace: Some(backtrace), .. }) => {
+                jthrow!( self => c, e, e.source(), backtrace);
+            }
+            _ => {
+                jthrow!( self => c, e, e.source(), &Backtrace::capture());
+            }
+        }
     }
 
     fn get_string_owned(
@@ -245,10 +793,7 @@ impl<'local, T, E: std::error::Error + 'static> JavaCatch<'local, T> for JResult
     fn j_catch(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<T> {
         match self {
             Ok(ok) => Ok(ok),
-            Err(err) => {
-                jthrow!(env => JExceptionClass::RuntimeException, err);
-                Err(JException::from_std(err))
-            }
+            Err(err) => JException::from_std(err).throw(env),
         }
     }
 }
@@ -261,6 +806,10 @@ impl<'local, T> JavaCatchINI<'local, T> for Result<T, jni::errors::Error> {
     fn j_catch_ini(self, env: &mut jni::JNIEnv<'local>, msg: &str) -> crate::JResult<T> {
         match self {
             Ok(ok) => Ok(ok),
+            // pending Java exception (rethrown unchanged)
+            Err(jni::errors::Error::JavaException) if env.exception_check().unwrap_or_default() => {
+                j_take_exception(env).throw(env)
+            }
             Err(err) => {
                 let exception = JExceptionClass::from(&err);
                 let message = format!("{err}\n   Cause: {msg}");
@@ -303,4 +852,242 @@ mod tests {
         assert_eq!(&result, "ok");
         Ok(())
     }
+
+    #[allow(unused_mut, non_snake_case)]
+    pub extern "system" fn Java_com_test_Lib1_divide<'a>(
+        mut env: JNIEnv<'a>,
+        _class: JClass<'_>,
+        value: jni::sys::jint,
+    ) -> jni::sys::jint {
+        let result = java_bindgen::exception::j_catch_panic(|| {
+            let zero: i32 = "0".parse().unwrap_or_default();
+            value / zero
+        });
+        java_bindgen::exception::j_panic_handler(result, "java/lang/RuntimeException", &mut env)
+    }
+
+    #[test_jvm]
+    fn should_throw_panic<'a>(
+        test_env: &mut JNIEnv<'a>,
+        env: JNIEnv<'a>,
+        class: JClass,
+    ) -> JResult<()> {
+        let result = Java_com_test_Lib1_divide(env, class, 1);
+        assert_eq!(result, 0);
+
+        let throwable = test_env.exception_occurred()?;
+        test_env.exception_clear()?;
+        let msg: String = throwable.call_getter("getMessage", test_env)?;
+        assert!(msg.contains("Rust panic: attempt to divide by zero (at src/exception.rs:"));
+        assert!(!test_env.exception_check()?);
+        Ok(())
+    }
+
+    #[test]
+    fn should_capture_panics_only_in_guard() {
+        let Err(panic) = java_bindgen::exception::j_catch_panic(|| panic!("in guard")) else {
+            panic!("expected RustPanic");
+        };
+        assert!(panic.location.is_some() && panic.backtrace.is_some());
+
+        assert!(std::panic::catch_unwind(|| panic!("outside guard")).is_err());
+        assert!(super::LAST_PANIC.with(|last| last.borrow().is_none()));
+    }
+
+    #[test]
+    fn should_map_io_error_kind() {
+        let not_found = JException::from(std::io::Error::from(std::io::ErrorKind::NotFound));
+        assert_eq!(not_found.class.get_class_path(), "java/io/FileNotFoundException");
+        let denied = JException::from(std::io::Error::from(std::io::ErrorKind::PermissionDenied));
+        assert_eq!(denied.class.get_class_path(), "java/nio/file/AccessDeniedException");
+        let other = JException::from(std::io::Error::other("disk full"));
+        assert_eq!(other.class.get_class_path(), "java/io/IOException");
+        let fmt = JException::from(std::fmt::Error);
+        assert_eq!(fmt.class.get_class_path(), "java/lang/RuntimeException");
+
+        let timed_out = JExceptionClass::from(std::io::ErrorKind::TimedOut);
+        assert_eq!(timed_out.get_class_path(), "java/net/SocketTimeoutException");
+        let unsupported = JExceptionClass::from(std::io::ErrorKind::Unsupported);
+        assert_eq!(unsupported.get_class_path(), "java/io/IOException");
+        let string_index = JExceptionClass::StringIndexOutOfBoundsException;
+        assert_eq!(string_index.get_class_path(), "java/lang/StringIndexOutOfBoundsException");
+        assert!(matches!(
+            JExceptionClass::from_class_name("java.lang.StringIndexOutOfBoundsException"),
+            Some(JExceptionClass::StringIndexOutOfBoundsException)
+        ));
+
+        let custom = JExceptionClass::Custom("java.util.concurrent.CancellationException".to_string());
+        assert_eq!(custom.get_class_path(), "java/util/concurrent/CancellationException");
+        assert_eq!(custom.to_string(), "java.util.concurrent.CancellationException");
+    }
+
+    #[derive(Debug)]
+    struct DecodeError(std::io::Error);
+
+    impl std::fmt::Display for DecodeError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Decode failed")
+        }
+    }
+
+    impl std::error::Error for DecodeError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test_jvm]
+    fn should_throw_cause_chain<'a>(
+        test_env: &mut JNIEnv<'a>,
+        _env: JNIEnv<'a>,
+        _class: JClass,
+    ) -> JResult<()> {
+        let error = DecodeError(std::io::Error::new(std::io::ErrorKind::NotFound, "image.png"));
+        test_env.j_throw_exception(JException::from_std_with_class(error, JExceptionClass::IllegalStateException));
+
+        let throwable = test_env.exception_occurred()?;
+        test_env.exception_clear()?;
+        let msg: String = throwable.call_getter("getMessage", test_env)?;
+        assert_eq!(msg, "Decode failed");
+
+        let cause = test_env
+            .call_method(&throwable, "getCause", "()Ljava/lang/Throwable;", &[])?
+            .l()?;
+        assert!(test_env.is_instance_of(&cause, "java/io/FileNotFoundException")?);
+        let msg: String = cause.call_getter("getMessage", test_env)?;
+        assert_eq!(msg, "image.png");
+        Ok(())
+    }
+
+    #[test_jvm]
+    fn should_add_rust_frames<'a>(
+        test_env: &mut JNIEnv<'a>,
+        _env: JNIEnv<'a>,
+        _class: JClass,
+    ) -> JResult<()> {
+        let class = JExceptionClass::IllegalStateException;
+        super::j_throw_new(test_env, &class, "Decode failed", None, &std::backtrace::Backtrace::force_capture());
+        let throwable = test_env.exception_occurred()?;
+        test_env.exception_clear()?;
+
+        let trace = test_env
+            .call_method(&throwable, "getStackTrace", "()[Ljava/lang/StackTraceElement;", &[])?
+            .l()?;
+        let trace = jni::objects::JObjectArray::from(trace);
+        let frame = test_env.get_object_array_element(&trace, 0)?;
+        let method: String = frame.call_getter("getMethodName", test_env)?;
+        assert_eq!(method, "should_add_rust_frames");
+        Ok(())
+    }
+
+    #[test_jvm]
+    fn should_capture_java_exception<'a>(
+        test_env: &mut JNIEnv<'a>,
+        _env: JNIEnv<'a>,
+        _class: JClass,
+    ) -> JResult<()> {
+        let optional = test_env
+            .call_static_method("java/util/OptionalInt", "empty", "()Ljava/util/OptionalInt;", &[])?
+            .l()?;
+        let result = optional.call_getter::<i32>("getAsInt", test_env);
+
+        let Err(err) = result else {
+            panic!("expected NoSuchElementException");
+        };
+        assert_eq!(err.class.to_string(), "java.util.NoSuchElementException");
+        let java = err.java_throwable().expect("Java exception").clone();
+        assert_eq!(java.class_name, "java.util.NoSuchElementException");
+        assert_eq!(java.message.as_deref(), Some("No value present"));
+        assert!(java.stack_trace.iter().any(|frame| frame.contains("java.util.OptionalInt.getAsInt")));
+
+        // rethrown unchanged
+        let throwable = test_env.exception_occurred()?;
+        test_env.exception_clear()?;
+        assert!(test_env.is_same_object(&throwable, java.throwable().expect("throwable"))?);
+
+        // Java exception as source of Rust error is the cause
+        let error = SaveError(java.clone());
+        test_env.j_throw_exception(JException::from_std(error));
+        let save_error = test_env.exception_occurred()?;
+        test_env.exception_clear()?;
+        let cause = test_env
+            .call_method(&save_error, "getCause", "()Ljava/lang/Throwable;", &[])?
+            .l()?;
+        assert!(test_env.is_same_object(&cause, &throwable)?);
+
+        test_env.throw_new("java/lang/IllegalStateException", "boom")?;
+        let err = j_take_exception(test_env);
+        assert!(!test_env.exception_check()?);
+        assert!(matches!(err.class, JExceptionClass::IllegalStateException));
+        assert_eq!(err.to_string(), "boom");
+        Ok(())
+    }
+
+    #[derive(Debug)]
+    struct SaveError(JavaThrowable);
+
+    impl std::fmt::Display for SaveError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Save failed")
+        }
+    }
+
+    impl std::error::Error for SaveError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn should_parse_rust_frames() {
+        let backtrace = "   0: std::backtrace::Backtrace::force_capture
+             at /rustc/abc/library/std/src/backtrace.rs:312:13
+   1: <jni::wrapper::jnienv::JNIEnv as java_bindgen::exception::JNIEnvUtils>::j_throw_exception
+             at ./src/exception.rs:520:17
+   2: test_macro::check_image
+             at ./src/lib.rs:10:5
+   3: test_macro::check_image::{{closure}}
+   4: std::panicking::catch_unwind
+   5: __rust_try
+   6: Java_com_test_macro_TestMacro_check_1image
+             at ./src/lib.rs:8:1
+   7: <unknown>";
+
+        let frames = super::rust_frames(backtrace);
+        assert_eq!(frames.len(), 3);
+        assert_eq!(
+            frames[0],
+            super::RustFrame {
+                module: "test_macro".to_string(),
+                function: "check_image".to_string(),
+                file: Some("./src/lib.rs".to_string()),
+                line: 10,
+            }
+        );
+        assert_eq!(frames[1].function, "{{closure}}");
+        assert_eq!(frames[1].file, None);
+        assert_eq!(frames[2].module, "rust");
+        assert_eq!(frames[2].function, "Java_com_test_macro_TestMacro_check_1image");
+    }
+
+    #[test_jvm]
+    fn should_throw_unchecked_io_exception<'a>(
+        test_env: &mut JNIEnv<'a>,
+        _env: JNIEnv<'a>,
+        _class: JClass,
+    ) -> JResult<()> {
+        let error = std::io::Error::other("disk full");
+        test_env.j_throw_exception(JException::from_std_with_class(error, JExceptionClass::UncheckedIOException));
+
+        let throwable = test_env.exception_occurred()?;
+        test_env.exception_clear()?;
+        assert!(test_env.is_instance_of(&throwable, "java/io/UncheckedIOException")?);
+        let cause = test_env
+            .call_method(&throwable, "getCause", "()Ljava/lang/Throwable;", &[])?
+            .l()?;
+        assert!(test_env.is_instance_of(&cause, "java/io/IOException")?);
+        let msg: String = cause.call_getter("getMessage", test_env)?;
+        assert!(msg.contains("disk full"));
+        Ok(())
+    }
 }
@@ -0,0 +1,113 @@
+#![allow(unsafe_code)]
+
+// Opaque Rust values owned by Java objects
+//
+// The value is boxed and the pointer is stored in the `long handle` field of the
+// generated Java class. Java declares every native method of the class `synchronized`
+// and `close()` resets the field to `0`, so a released handle is never dereferenced.
+
+use std::sync::{Mutex, MutexGuard, TryLockError};
+
+use jni::objects::{JObject, JValue};
+
+use crate::prelude::*;
+
+/// Name of the Java field holding the pointer
+pub const HANDLE_FIELD: &str = "handle";
+
+/// Move value to the heap and return pointer as Java `long`
+pub fn into_handle<T: Send + 'static>(value: T) -> jni::sys::jlong {
+    Box::into_raw(Box::new(Mutex::new(value))) as jni::sys::jlong
+}
+
+/// Create new Java handle object (`class_path` must declare `(J)V` constructor)
+pub fn new_handle_object<'local, T: Send + 'static>(
+    env: &mut JNIEnv<'local>,
+    class_path: &str,
+    value: T,
+) -> JResult<JObject<'local>> {
+    let handle = into_handle(value);
+    let obj = env
+        .find_class(class_path)
+        .and_then(|class| env.new_object(class, "(J)V", &[JValue::Long(handle)]))
+        .j_catch_ini(env, &format!("Create handle object: {class_path}"));
+
+    if obj.is_err() {
+        release::<T>(handle);
+    }
+    obj
+}
+
+/// Borrow value owned by Java object
+pub fn borrow_handle<'h, 'local, T: Send + 'static>(
+    env: &mut JNIEnv<'local>,
+    this: &'h JObject<'local>,
+) -> JResult<MutexGuard<'h, T>> {
+    let handle = read_handle(env, this)?;
+    // Safety: pointer was created by `into_handle::<T>` and stays valid until `drop_handle`,
+    // which can't run concurrently (synchronized on the Java side).
+    let value = unsafe { &*(handle as *const Mutex<T>) };
+    match value.try_lock() {
+        Ok(guard) => Ok(guard),
+        // a previous call panicked, the value may be half-updated
+        Err(TryLockError::Poisoned(_)) => JException::from_class_and_msg(
+            JExceptionClass::IllegalStateException,
+            "Handle is poisoned (panic in a previous call)",
+        )
+        .throw(env),
+        Err(TryLockError::WouldBlock) => {
+            Err(jni::errors::Error::TryLock).j_catch_ini(env, "Handle is already borrowed")
+        }
+    }
+}
+
+/// Drop value owned by Java object (`close()`)
+pub fn drop_handle<'local, T: Send + 'static>(
+    env: &mut JNIEnv<'local>,
+    this: &JObject<'local>,
+) -> JResult<()> {
+    let handle = env
+        .get_field(this, HANDLE_FIELD, "J")
+        .and_then(|v| v.j())
+        .j_catch_ini(env, "Read handle field")?;
+    if handle == 0 {
+        return Ok(());
+    }
+
+    // Safety: see `borrow_handle`. `synchronized` is reentrant, so `close()` can be called
+    // by a callback while a method of the same object holds the guard.
+    let value = unsafe { &*(handle as *const Mutex<T>) };
+    if matches!(value.try_lock(), Err(TryLockError::WouldBlock)) {
+        return JException::from_class_and_msg(
+            JExceptionClass::IllegalStateException,
+            "Handle is borrowed",
+        )
+        .throw(env);
+    }
+
+    env.set_field(this, HANDLE_FIELD, "J", JValue::Long(0))
+        .j_catch_ini(env, "Reset handle field")?;
+    release::<T>(handle);
+    Ok(())
+}
+
+fn read_handle<'local>(env: &mut JNIEnv<'local>, this: &JObject<'local>) -> JResult<jni::sys::jlong> {
+    let handle = env
+        .get_field(this, HANDLE_FIELD, "J")
+        .and_then(|v| v.j())
+        .j_catch_ini(env, "Read handle field")?;
+
+    if handle == 0 {
+        return JException::from_class_and_msg(
+            JExceptionClass::IllegalStateException,
+            "Handle is closed",
+        )
+        .throw(env);
+    }
+    Ok(handle)
+}
+
+fn release<T>(handle: jni::sys::jlong) {
+    // Safety: pointer was created by `into_handle::<T>` and is released only once.
+    drop(unsafe { Box::from_raw(handle as *mut Mutex<T>) });
+}
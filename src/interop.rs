@@ -1,4 +1,8 @@
-use crate::{exception::JavaCatchINI, j2r::IntoRustType, r2j::IntoJavaType};
+use crate::{
+    exception::{JException, JExceptionClass, JavaCatchINI},
+    j2r::IntoRustType,
+    r2j::IntoJavaType,
+};
 use std::borrow::BorrowMut;
 
 // Java primary types wrappers
@@ -54,6 +58,130 @@ impl<'local> JObjectGetters<'local> for jni::objects::JObject<'local> {
     }
 }
 
+// Java arguments tuple: (), (a,), (a, b), ...
+pub trait JArgs<'local> {
+    fn j_types(&self) -> Vec<jni::signature::JavaType>;
+    fn into_j_values(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<JValueOwned<'local>>>;
+}
+
+macro_rules! j_args_impl {
+    ($($arg:ident),*) => {
+        impl<'local, $($arg: JTypeInfo<'local>),*> JArgs<'local> for ($($arg,)*) {
+            fn j_types(&self) -> Vec<jni::signature::JavaType> {
+                vec![$($arg::j_type()),*]
+            }
+
+            #[allow(non_snake_case, unused_variables)]
+            fn into_j_values(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<JValueOwned<'local>>> {
+                let ($($arg,)*) = self;
+                Ok(vec![$($arg.into_j_value(env)?),*])
+            }
+        }
+    };
+}
+
+j_args_impl!();
+j_args_impl!(A);
+j_args_impl!(A, B);
+j_args_impl!(A, B, C);
+j_args_impl!(A, B, C, D);
+j_args_impl!(A, B, C, D, E);
+j_args_impl!(A, B, C, D, E, F);
+j_args_impl!(A, B, C, D, E, F, G);
+j_args_impl!(A, B, C, D, E, F, G, H);
+
+// (Ljava/lang/String;I)Ljava/util/List;
+fn j_method_sig<'local, R: JTypeInfo<'local>>(args: Vec<jni::signature::JavaType>) -> String {
+    let args: String = args.iter().map(|arg| arg.to_string()).collect();
+    format!("({args}){}", R::j_type())
+}
+
+/// Call Java static method: `call_static::<i32>("java/lang/Math", "max", (1, 2), env)`
+pub fn call_static<'local, R>(
+    class: &str,
+    name: &str,
+    args: impl JArgs<'local>,
+    env: &mut jni::JNIEnv<'local>,
+) -> crate::JResult<R>
+where
+    R: JTypeInfo<'local>,
+    JValueGen<jni::objects::JObject<'local>>: IntoRustType<'local, R>,
+{
+    let sig = j_method_sig::<R>(args.j_types());
+    let args = args.into_j_values(env)?;
+    let values: Vec<jni::objects::JValue> = args.iter().map(|arg| arg.borrow()).collect();
+    let e = env
+        .call_static_method(class, name, &sig, &values)
+        .j_catch_ini(env, &format!("Call Java method: {class}.{name}{sig}"))?;
+
+    e.into_rust(env)
+}
+
+/// Call Java method: `call_method::<String>(&obj, "substring", (1, 3), env)`
+pub fn call_method<'local, R>(
+    obj: &jni::objects::JObject<'_>,
+    name: &str,
+    args: impl JArgs<'local>,
+    env: &mut jni::JNIEnv<'local>,
+) -> crate::JResult<R>
+where
+    R: JTypeInfo<'local>,
+    JValueGen<jni::objects::JObject<'local>>: IntoRustType<'local, R>,
+{
+    let sig = j_method_sig::<R>(args.j_types());
+    let args = args.into_j_values(env)?;
+    let values: Vec<jni::objects::JValue> = args.iter().map(|arg| arg.borrow()).collect();
+    let e = env
+        .call_method(obj, name, &sig, &values)
+        .j_catch_ini(env, &format!("Call Java method: {name}{sig}"))?;
+
+    e.into_rust(env)
+}
+
+/// Read Java static field: `get_static_field::<i32>("java/lang/Integer", "MAX_VALUE", env)`
+pub fn get_static_field<'local, T>(class: &str, name: &str, env: &mut jni::JNIEnv<'local>) -> crate::JResult<T>
+where
+    T: JTypeInfo<'local>,
+    JValueGen<jni::objects::JObject<'local>>: IntoRustType<'local, T>,
+{
+    let ty = T::j_type().to_string();
+    let e = env
+        .get_static_field(class, name, &ty)
+        .j_catch_ini(env, &format!("Get Java field: {class}.{name} ({ty})"))?;
+
+    e.into_rust(env)
+}
+
+/// Read Java field: `get_field::<i32>(&point, "x", env)`
+pub fn get_field<'local, T>(obj: &jni::objects::JObject<'_>, name: &str, env: &mut jni::JNIEnv<'local>) -> crate::JResult<T>
+where
+    T: JTypeInfo<'local>,
+    JValueGen<jni::objects::JObject<'local>>: IntoRustType<'local, T>,
+{
+    let ty = T::j_type().to_string();
+    let e = env
+        .get_field(obj, name, &ty)
+        .j_catch_ini(env, &format!("Get Java field: {name} ({ty})"))?;
+
+    e.into_rust(env)
+}
+
+/// Write Java field: `set_field(&obj, "name", "Rust".to_string(), env)`
+pub fn set_field<'local, T>(
+    obj: &jni::objects::JObject<'_>,
+    name: &str,
+    value: T,
+    env: &mut jni::JNIEnv<'local>,
+) -> crate::JResult<()>
+where
+    T: JTypeInfo<'local>,
+{
+    let ty = T::j_type().to_string();
+    let value = value.into_j_value(env)?;
+    env.set_field(obj, name, &ty, value.borrow())
+        .j_catch_ini(env, &format!("Set Java field: {name} ({ty})"))
+}
+
 use jni::objects::{JValueGen, JValueOwned};
 
 pub use jtypes::*;
@@ -214,7 +342,21 @@ mod jtypes {
         }
     }
 
-    impl<'local> JTypeInfo<'local> for u8 {
+    impl<'local> JTypeInfo<'local> for () {
+        fn j_return_type() -> jni::signature::ReturnType {
+            ReturnType::Primitive(jni::signature::Primitive::Void)
+        }
+
+        fn j_type() -> jni::signature::JavaType {
+            JavaType::Primitive(jni::signature::Primitive::Void)
+        }
+
+        fn into_j_value(self, _: &mut jni::JNIEnv<'local>) -> JResult<JValueOwned<'local>> {
+            Ok(JValueOwned::Void)
+        }
+    }
+
+    impl<'local> JTypeInfo<'local> for i8 {
         fn j_return_type() -> jni::signature::ReturnType {
             ReturnType::Primitive(jni::signature::Primitive::Byte)
         }
@@ -224,7 +366,7 @@ mod jtypes {
         }
 
         fn into_j_value(self, _: &mut jni::JNIEnv<'local>) -> JResult<JValueOwned<'local>> {
-            Ok(JValueOwned::Byte(self as i8))
+            Ok(JValueOwned::Byte(self))
         }
     }
 
@@ -486,6 +628,38 @@ mod jtypes {
         }
     }
 
+    // Primitive arrays
+
+    macro_rules! primitive_array_type_info_impl {
+        ($rust_type:ty, $primitive:ident) => {
+            impl<'local> JTypeInfo<'local> for Vec<$rust_type> {
+                fn j_return_type() -> jni::signature::ReturnType {
+                    ReturnType::Array
+                }
+
+                fn j_type() -> jni::signature::JavaType {
+                    JavaType::Array(Box::new(JavaType::Primitive(
+                        jni::signature::Primitive::$primitive,
+                    )))
+                }
+
+                fn into_j_value(self, env: &mut jni::JNIEnv<'local>) -> JResult<JValueOwned<'local>> {
+                    let obj: JObject<'local> = self.into_java(env)?;
+                    Ok(JValueOwned::Object(obj))
+                }
+            }
+        };
+    }
+
+    primitive_array_type_info_impl!(i16, Short);
+    primitive_array_type_info_impl!(u16, Char);
+    primitive_array_type_info_impl!(char, Char);
+    primitive_array_type_info_impl!(i32, Int);
+    primitive_array_type_info_impl!(i64, Long);
+    primitive_array_type_info_impl!(f32, Float);
+    primitive_array_type_info_impl!(f64, Double);
+    primitive_array_type_info_impl!(bool, Boolean);
+
     #[cfg(test)]
     pub mod tests {
         use jni::signature::{Primitive, TypeSignature};
@@ -596,6 +770,10 @@ mod jtypes {
                 TypeSignature::from_str("(Ljava/lang/String;[B)I").unwrap(),
                 signature_by_type!( Option<String> , Option<Vec<u8>> => Option<i32> )
             );
+            assert_eq!(
+                TypeSignature::from_str("([I[J[F[D[S[C[C[Z)V").expect("Valid signature"),
+                signature_by_type!( Vec<i32>, Vec<i64>, Vec<f32>, Vec<f64>, Vec<i16>, Vec<u16>, Vec<char>, Vec<bool> => JVoid )
+            );
 
             println!("{}", signature_by_type!( => ));
             println!("{}", signature_by_type!( => String));
@@ -628,6 +806,450 @@ mod jtypes {
     }
 }
 
+// Unsigned / Wide Integers Support
+
+/// Java representation of `u8`, `u16`, `u32`, `u64`, `usize`, `i128` and `u128`.
+///
+/// Configured with `integer-policy` in `[package.metadata.java-bindgen]`.
+/// Generated native methods enter it for the duration of the call.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum JIntegerPolicy {
+    /// `byte`, `char`, `int`, `long` (bits reinterpreted), `BigInteger` for 128 bit
+    #[default]
+    SameWidth,
+    /// `short`, `int`, `long`, `BigInteger` for 64 bit and wider
+    Widen,
+    /// `java.math.BigInteger`
+    BigInteger,
+}
+
+thread_local! {
+    static INTEGER_POLICY: std::cell::Cell<JIntegerPolicy> = const { std::cell::Cell::new(JIntegerPolicy::SameWidth) };
+}
+
+impl JIntegerPolicy {
+    /// Policy of the running native call (`SameWidth` outside of generated code)
+    pub fn current() -> Self {
+        INTEGER_POLICY.with(|policy| policy.get())
+    }
+
+    /// Use this policy on the current thread until the guard is dropped
+    pub fn enter(self) -> JIntegerPolicyGuard {
+        let previous = INTEGER_POLICY.with(|policy| policy.replace(self));
+        JIntegerPolicyGuard { previous }
+    }
+}
+
+/// Restores the previous `JIntegerPolicy` on drop
+#[must_use]
+pub struct JIntegerPolicyGuard {
+    previous: JIntegerPolicy,
+}
+
+impl Drop for JIntegerPolicyGuard {
+    fn drop(&mut self) {
+        INTEGER_POLICY.with(|policy| policy.set(self.previous));
+    }
+}
+
+pub(crate) fn j_out_of_range<T>(
+    value: impl std::fmt::Display,
+    rust_type: &str,
+    env: &mut jni::JNIEnv<'_>,
+) -> crate::JResult<T> {
+    JException::from_class_and_msg(
+        JExceptionClass::ArithmeticException,
+        &format!("{value} is out of range for {rust_type}"),
+    )
+    .throw(env)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum JIntegerRepr {
+    Byte,
+    Char,
+    Short,
+    Int,
+    Long,
+    BigInteger,
+}
+
+impl JIntegerRepr {
+    fn primitive(self) -> Option<jni::signature::Primitive> {
+        use jni::signature::Primitive;
+        match self {
+            JIntegerRepr::Byte => Some(Primitive::Byte),
+            JIntegerRepr::Char => Some(Primitive::Char),
+            JIntegerRepr::Short => Some(Primitive::Short),
+            JIntegerRepr::Int => Some(Primitive::Int),
+            JIntegerRepr::Long => Some(Primitive::Long),
+            JIntegerRepr::BigInteger => None,
+        }
+    }
+
+    // (Class, valueOf signature)
+    fn boxed(self) -> (&'static str, &'static str) {
+        match self {
+            JIntegerRepr::Byte => ("java/lang/Byte", "(B)Ljava/lang/Byte;"),
+            JIntegerRepr::Char => ("java/lang/Character", "(C)Ljava/lang/Character;"),
+            JIntegerRepr::Short => ("java/lang/Short", "(S)Ljava/lang/Short;"),
+            JIntegerRepr::Int => ("java/lang/Integer", "(I)Ljava/lang/Integer;"),
+            JIntegerRepr::Long => ("java/lang/Long", "(J)Ljava/lang/Long;"),
+            JIntegerRepr::BigInteger => ("java/math/BigInteger", "(Ljava/lang/String;)V"),
+        }
+    }
+
+    fn j_value<'local>(self, value: i64) -> JValueOwned<'local> {
+        match self {
+            JIntegerRepr::Byte => JValueOwned::Byte(value as i8),
+            JIntegerRepr::Char => JValueOwned::Char(value as u16),
+            JIntegerRepr::Short => JValueOwned::Short(value as i16),
+            JIntegerRepr::Int => JValueOwned::Int(value as i32),
+            JIntegerRepr::Long | JIntegerRepr::BigInteger => JValueOwned::Long(value),
+        }
+    }
+}
+
+trait JInteger: Copy + std::fmt::Display + std::str::FromStr + TryFrom<i64> {
+    const SAME_WIDTH: JIntegerRepr;
+    const WIDEN: JIntegerRepr;
+
+    // Reinterpret bits of the same width Java type
+    fn from_same_width(value: i64) -> Option<Self>;
+    fn as_i64(self) -> i64;
+
+    fn repr() -> JIntegerRepr {
+        match JIntegerPolicy::current() {
+            JIntegerPolicy::SameWidth => Self::SAME_WIDTH,
+            JIntegerPolicy::Widen => Self::WIDEN,
+            JIntegerPolicy::BigInteger => JIntegerRepr::BigInteger,
+        }
+    }
+
+    // Java value of the same width is reinterpreted, a wider one is checked
+    fn from_i64(value: i64, repr: JIntegerRepr, env: &mut jni::JNIEnv<'_>) -> crate::JResult<Self> {
+        let converted = match repr == Self::SAME_WIDTH {
+            true => Self::from_same_width(value),
+            false => Self::try_from(value).ok(),
+        };
+        match converted {
+            Some(value) => Ok(value),
+            None => j_out_of_range(value, std::any::type_name::<Self>(), env),
+        }
+    }
+
+    fn from_j_object<'local>(
+        obj: jni::objects::JObject<'local>,
+        env: &mut jni::JNIEnv<'local>,
+    ) -> crate::JResult<Self> {
+        let rust_type = std::any::type_name::<Self>();
+        if obj.is_null() {
+            return JException::from_class_and_msg(
+                JExceptionClass::NullPointerException,
+                &format!("Cast failed [null -> {rust_type}]"),
+            )
+            .throw(env);
+        }
+
+        let is_instance_of = |env: &mut jni::JNIEnv<'local>, class: &str| {
+            env.is_instance_of(&obj, class)
+                .j_catch_ini(env, &format!("Cast failed [JObject -> {rust_type}]"))
+        };
+
+        if is_instance_of(env, "java/math/BigInteger")? {
+            let value: String = env
+                .call_method(&obj, "toString", "()Ljava/lang/String;", &[])
+                .j_catch_ini(env, "BigInteger.toString() failed")?
+                .l()?
+                .into_rust(env)?;
+            return match value.parse::<Self>() {
+                Ok(value) => Ok(value),
+                Err(_) => j_out_of_range(value, rust_type, env),
+            };
+        }
+
+        if is_instance_of(env, "java/lang/Character")? {
+            let value: char = obj.call_getter("charValue", env)?;
+            return Self::from_i64(value as i64, JIntegerRepr::Char, env);
+        }
+        if !is_instance_of(env, "java/lang/Number")? {
+            return JException::from_class_and_msg(
+                JExceptionClass::IllegalArgumentException,
+                &format!("Cast failed [JObject -> {rust_type}]: expected java.lang.Number"),
+            )
+            .throw(env);
+        }
+
+        let boxed = [
+            ("java/lang/Byte", JIntegerRepr::Byte),
+            ("java/lang/Short", JIntegerRepr::Short),
+            ("java/lang/Integer", JIntegerRepr::Int),
+            ("java/lang/Long", JIntegerRepr::Long),
+        ];
+        // Other Number types (AtomicLong, ...) are always checked
+        let mut repr = JIntegerRepr::BigInteger;
+        for (class, class_repr) in boxed {
+            if is_instance_of(env, class)? {
+                repr = class_repr;
+                break;
+            }
+        }
+        let value = obj.call_getter::<i64>("longValue", env)?;
+        Self::from_i64(value, repr, env)
+    }
+
+    fn into_j_object<'local>(
+        self,
+        env: &mut jni::JNIEnv<'local>,
+    ) -> crate::JResult<jni::objects::JObject<'local>> {
+        let repr = Self::repr();
+        let (class_name, sig) = repr.boxed();
+        let class = env
+            .find_class(class_name)
+            .j_catch_ini(env, &format!("{class_name} class not found"))?;
+
+        if repr == JIntegerRepr::BigInteger {
+            let value = env
+                .new_string(self.to_string())
+                .j_catch_ini(env, "Failed to create String")?;
+            return env
+                .new_object(class, sig, &[(&value).into()])
+                .j_catch_ini(env, "Failed to create BigInteger");
+        }
+
+        let value = repr.j_value(self.as_i64());
+        env.call_static_method(class, "valueOf", sig, &[value.borrow()])
+            .j_catch_ini(env, &format!("Failed to create {class_name}"))?
+            .l()
+            .j_catch_ini(env, &format!("Failed to create {class_name}"))
+    }
+}
+
+macro_rules! j_integer_impl {
+    ($rust_type:ty, $same_width:ident, $widen:ident, $from_same_width:expr) => {
+        impl JInteger for $rust_type {
+            const SAME_WIDTH: JIntegerRepr = JIntegerRepr::$same_width;
+            const WIDEN: JIntegerRepr = JIntegerRepr::$widen;
+
+            fn from_same_width(value: i64) -> Option<Self> {
+                $from_same_width(value)
+            }
+
+            fn as_i64(self) -> i64 {
+                self as i64
+            }
+        }
+
+        impl<'local> IntoRustType<'local, $rust_type> for jni::objects::JObject<'local> {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<$rust_type> {
+                <$rust_type>::from_j_object(self, env)
+            }
+        }
+
+        impl<'local> IntoRustType<'local, $rust_type> for JValueGen<jni::objects::JObject<'local>> {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<$rust_type> {
+                let (value, repr) = match self {
+                    JValueGen::Byte(value) => (value as i64, JIntegerRepr::Byte),
+                    JValueGen::Char(value) => (value as i64, JIntegerRepr::Char),
+                    JValueGen::Short(value) => (value as i64, JIntegerRepr::Short),
+                    JValueGen::Int(value) => (value as i64, JIntegerRepr::Int),
+                    JValueGen::Long(value) => (value, JIntegerRepr::Long),
+                    value => {
+                        let msg = concat!("Cast failed [JObject -> ", stringify!($rust_type), "]");
+                        let obj = value.l().j_catch_ini(env, msg)?;
+                        return <$rust_type>::from_j_object(obj, env);
+                    }
+                };
+                <$rust_type>::from_i64(value, repr, env)
+            }
+        }
+
+        impl<'local> IntoJavaType<'local, jni::objects::JObject<'local>> for $rust_type {
+            fn into_java(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::objects::JObject<'local>> {
+                self.into_j_object(env)
+            }
+        }
+
+        impl<'local> JTypeInfo<'local> for $rust_type {
+            fn j_return_type() -> jni::signature::ReturnType {
+                match <$rust_type>::repr().primitive() {
+                    Some(primitive) => jni::signature::ReturnType::Primitive(primitive),
+                    None => jni::signature::ReturnType::Object,
+                }
+            }
+
+            fn j_type() -> jni::signature::JavaType {
+                match <$rust_type>::repr().primitive() {
+                    Some(primitive) => jni::signature::JavaType::Primitive(primitive),
+                    None => jni::signature::JavaType::Object("java/math/BigInteger".to_string()),
+                }
+            }
+
+            fn into_j_value(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<JValueOwned<'local>> {
+                match <$rust_type>::repr() {
+                    JIntegerRepr::BigInteger => Ok(JValueOwned::Object(self.into_j_object(env)?)),
+                    repr => Ok(repr.j_value(self.as_i64())),
+                }
+            }
+        }
+    };
+}
+
+j_integer_impl!(u8, Byte, Short, |value: i64| Some(value as u8));
+j_integer_impl!(u16, Char, Int, |value: i64| Some(value as u16));
+j_integer_impl!(u32, Int, Long, |value: i64| Some(value as u32));
+j_integer_impl!(u64, Long, BigInteger, |value: i64| Some(value as u64));
+j_integer_impl!(usize, Long, BigInteger, |value: i64| usize::try_from(value as u64).ok());
+j_integer_impl!(i128, BigInteger, BigInteger, |value: i64| Some(value as i128));
+j_integer_impl!(u128, BigInteger, BigInteger, |value: i64| u128::try_from(value).ok());
+
+// Java int[], long[] or BigInteger[] (by JIntegerPolicy) of unsigned / wide integers
+
+fn j_integer_array_into_java<'local, T: JInteger + 'static>(
+    values: Vec<T>,
+    env: &mut jni::JNIEnv<'local>,
+) -> crate::JResult<jni::objects::JObject<'local>> {
+    let repr = T::repr();
+    match repr {
+        JIntegerRepr::Int => {
+            let values: Vec<i32> = values.into_iter().map(|value| value.as_i64() as i32).collect();
+            let array: jni::objects::JIntArray<'local> = values.into_java(env)?;
+            return Ok(array.into());
+        }
+        JIntegerRepr::Long => {
+            let values: Vec<i64> = values.into_iter().map(|value| value.as_i64()).collect();
+            let array: jni::objects::JLongArray<'local> = values.into_java(env)?;
+            return Ok(array.into());
+        }
+        _ => {}
+    }
+
+    let (class_name, _) = repr.boxed();
+    let msg = format!("Vec<{}> -> {class_name}[]", std::any::type_name::<T>());
+    let array = env
+        .new_object_array(values.len() as jni::sys::jsize, class_name, jni::objects::JObject::null())
+        .j_catch_ini(env, &msg)?;
+    for (i, value) in values.into_iter().enumerate() {
+        j_in_local_frame(env, |env| {
+            let obj = value.into_j_object(env)?;
+            env.set_object_array_element(&array, i as jni::sys::jsize, obj)
+                .j_catch_ini(env, &msg)
+        })?;
+    }
+    Ok(array.into())
+}
+
+// The array type is checked (int[] and long[] are accepted for any policy)
+fn j_integer_array_into_rust<'local, T: JInteger + 'static>(
+    obj: jni::objects::JObject<'local>,
+    env: &mut jni::JNIEnv<'local>,
+) -> crate::JResult<Vec<T>> {
+    let msg = format!("Cast failed [JObject -> Vec<{}>]", std::any::type_name::<T>());
+    if env.is_instance_of(&obj, "[I").j_catch_ini(env, &msg)? {
+        let values: Vec<i32> = jni::objects::JIntArray::from(obj).into_rust(env)?;
+        return values
+            .into_iter()
+            .map(|value| T::from_i64(value as i64, JIntegerRepr::Int, env))
+            .collect();
+    }
+    if env.is_instance_of(&obj, "[J").j_catch_ini(env, &msg)? {
+        let values: Vec<i64> = jni::objects::JLongArray::from(obj).into_rust(env)?;
+        return values
+            .into_iter()
+            .map(|value| T::from_i64(value, JIntegerRepr::Long, env))
+            .collect();
+    }
+
+    let array = jni::objects::JObjectArray::from(obj);
+    let len = env.get_array_length(&array).j_catch_ini(env, &msg)?;
+    let mut items = Vec::with_capacity(len as usize);
+    for i in 0..len {
+        let item = j_in_local_frame(env, |env| {
+            let element = env.get_object_array_element(&array, i).j_catch_ini(env, &msg)?;
+            T::from_j_object(element, env)
+        })?;
+        items.push(item);
+    }
+    Ok(items)
+}
+
+macro_rules! j_integer_array_impl {
+    ($rust_type:ty) => {
+        impl<'local> IntoRustType<'local, Vec<$rust_type>> for jni::objects::JObject<'local> {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<$rust_type>> {
+                j_integer_array_into_rust(self, env)
+            }
+        }
+
+        impl<'local> IntoRustType<'local, Vec<$rust_type>> for JValueGen<jni::objects::JObject<'local>> {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<$rust_type>> {
+                let msg = concat!("Cast failed [JObject -> Vec<", stringify!($rust_type), ">]");
+                let obj = self.l().j_catch_ini(env, msg)?;
+                j_integer_array_into_rust(obj, env)
+            }
+        }
+
+        impl<'local> IntoJavaType<'local, jni::objects::JObject<'local>> for Vec<$rust_type> {
+            fn into_java(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::objects::JObject<'local>> {
+                j_integer_array_into_java(self, env)
+            }
+        }
+
+        impl<'local> JTypeInfo<'local> for Vec<$rust_type> {
+            fn j_return_type() -> jni::signature::ReturnType {
+                jni::signature::ReturnType::Array
+            }
+
+            fn j_type() -> jni::signature::JavaType {
+                jni::signature::JavaType::Array(Box::new(<$rust_type>::j_type()))
+            }
+
+            fn into_j_value(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<JValueOwned<'local>> {
+                Ok(JValueOwned::Object(j_integer_array_into_java(self, env)?))
+            }
+        }
+
+        j_integer_array_impl!($rust_type, JIntArray);
+        j_integer_array_impl!($rust_type, JLongArray);
+        j_integer_array_impl!($rust_type, JObjectArray);
+    };
+    // Generated native methods (array type of the crate policy)
+    ($rust_type:ty, $array:ident) => {
+        impl<'local> IntoRustType<'local, Vec<$rust_type>> for jni::objects::$array<'local> {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<$rust_type>> {
+                j_integer_array_into_rust(self.into(), env)
+            }
+        }
+
+        impl<'local> IntoJavaType<'local, jni::objects::$array<'local>> for Vec<$rust_type> {
+            fn into_java(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::objects::$array<'local>> {
+                Ok(j_integer_array_into_java(self, env)?.into())
+            }
+        }
+    };
+}
+
+j_integer_array_impl!(u32);
+j_integer_array_impl!(u64);
+j_integer_array_impl!(usize);
+j_integer_array_impl!(i128);
+j_integer_array_impl!(u128);
+
+// Convert a collection element in its own local frame (collections are converted in loops).
+// `T: 'static` keeps local references from escaping the frame.
+fn j_in_local_frame<'local, T: 'static>(
+    env: &mut jni::JNIEnv<'local>,
+    convert: impl FnOnce(&mut jni::JNIEnv<'local>) -> crate::JResult<T>,
+) -> crate::JResult<T> {
+    use crate::callback::{pop_local_frame, LOCAL_FRAME_CAPACITY};
+
+    env.push_local_frame(LOCAL_FRAME_CAPACITY)
+        .j_catch_ini(env, "Failed to create local frame")?;
+    let result = convert(env);
+    pop_local_frame(&jni::objects::JObject::null(), env);
+    result
+}
+
 // Java List<T> Support
 
 #[derive(Default)]
@@ -758,3 +1380,664 @@ where
         Ok(JValueOwned::Object(jni::objects::JObject::from(obj)))
     }
 }
+
+// Java Map<K, V> Support
+
+pub struct JMap<K, V>(pub std::collections::HashMap<K, V>);
+
+impl<K, V> Default for JMap<K, V> {
+    fn default() -> Self {
+        Self(std::collections::HashMap::new())
+    }
+}
+
+impl<K: Eq + std::hash::Hash, V> JMap<K, V> {
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.0.insert(key, value)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.0.get(key)
+    }
+}
+
+impl<K: Eq + std::hash::Hash, V> FromIterator<(K, V)> for JMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<K, V> IntoIterator for JMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::collections::hash_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+// Iterate Map.entrySet()
+fn map_entries_from_j_object<'local, K: 'static, V: 'static>(
+    obj: jni::objects::JObject<'local>,
+    env: &mut jni::JNIEnv<'local>,
+) -> crate::JResult<Vec<(K, V)>>
+where
+    jni::objects::JObject<'local>: IntoRustType<'local, K> + IntoRustType<'local, V>,
+{
+    let map = jni::objects::JMap::from_env(env, &obj).j_catch_ini(env, "Cast failed [JObject -> Map]")?;
+    let mut iter = map.iter(env).j_catch_ini(env, "Map.entrySet() failed")?;
+
+    let mut entries = vec![];
+    loop {
+        let entry = j_in_local_frame(env, |env| {
+            let Some((key, value)) = iter.next(env).j_catch_ini(env, "Map.entrySet() failed")? else {
+                return Ok(None);
+            };
+            let key: K = key.into_rust(env)?;
+            let value: V = value.into_rust(env)?;
+            Ok(Some((key, value)))
+        })?;
+        let Some(entry) = entry else {
+            break;
+        };
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+fn map_into_j_object<'local, K, V>(
+    entries: impl IntoIterator<Item = (K, V)>,
+    class_name: &str,
+    env: &mut jni::JNIEnv<'local>,
+) -> crate::JResult<jni::objects::JObject<'local>>
+where
+    K: IntoJavaType<'local, jni::objects::JObject<'local>>,
+    V: IntoJavaType<'local, jni::objects::JObject<'local>>,
+{
+    let class = env
+        .find_class(class_name)
+        .j_catch_ini(env, &format!("{class_name} class not found"))?;
+    let obj = env
+        .new_object(class, "()V", &[])
+        .j_catch_ini(env, &format!("Failed to create {class_name}"))?;
+    let map = jni::objects::JMap::from_env(env, &obj).j_catch_ini(env, "Cast failed [JObject -> Map]")?;
+
+    for (key, value) in entries {
+        j_in_local_frame(env, |env| {
+            let key = key.into_java(env)?;
+            let value = value.into_java(env)?;
+            map.put(env, &key, &value).j_catch_ini(env, "Map.put(K, V) failed")?;
+            Ok(())
+        })?;
+    }
+
+    Ok(obj)
+}
+
+// $java_class: Map implementation created on the way out
+macro_rules! j_map_impl {
+    ($map:ident, $java_class:literal) => {
+        impl<'local, K: 'static, V: 'static> IntoRustType<'local, $map<K, V>> for jni::objects::JObject<'local>
+        where
+            $map<K, V>: FromIterator<(K, V)>,
+            jni::objects::JObject<'local>: IntoRustType<'local, K> + IntoRustType<'local, V>,
+        {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<$map<K, V>> {
+                let entries = map_entries_from_j_object(self, env)?;
+                Ok(entries.into_iter().collect())
+            }
+        }
+
+        impl<'local, K: 'static, V: 'static> IntoRustType<'local, $map<K, V>>
+            for jni::objects::JValueGen<jni::objects::JObject<'local>>
+        where
+            $map<K, V>: FromIterator<(K, V)>,
+            jni::objects::JObject<'local>: IntoRustType<'local, K> + IntoRustType<'local, V>,
+        {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<$map<K, V>> {
+                let obj = self.l().j_catch_ini(env, "Cast failed [JObject -> Map]")?;
+                let entries = map_entries_from_j_object(obj, env)?;
+                Ok(entries.into_iter().collect())
+            }
+        }
+
+        impl<'local, K, V> IntoJavaType<'local, jni::objects::JObject<'local>> for $map<K, V>
+        where
+            K: IntoJavaType<'local, jni::objects::JObject<'local>>,
+            V: IntoJavaType<'local, jni::objects::JObject<'local>>,
+        {
+            fn into_java(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::objects::JObject<'local>> {
+                map_into_j_object(self, $java_class, env)
+            }
+        }
+
+        impl<'local, K, V> JTypeInfo<'local> for $map<K, V>
+        where
+            K: IntoJavaType<'local, jni::objects::JObject<'local>>,
+            V: IntoJavaType<'local, jni::objects::JObject<'local>>,
+        {
+            fn j_return_type() -> jni::signature::ReturnType {
+                jni::signature::ReturnType::Object
+            }
+
+            fn j_type() -> jni::signature::JavaType {
+                jni::signature::JavaType::Object("java/util/Map".to_string())
+            }
+
+            fn into_j_value(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<JValueOwned<'local>> {
+                let obj = map_into_j_object(self, $java_class, env)?;
+                Ok(JValueOwned::Object(obj))
+            }
+        }
+
+        impl<K, V> JObjectArrayElement for $map<K, V> {}
+    };
+}
+
+use std::collections::{BTreeMap, HashMap};
+
+j_map_impl!(JMap, "java/util/HashMap");
+j_map_impl!(HashMap, "java/util/HashMap");
+// Keeps BTreeMap order
+j_map_impl!(BTreeMap, "java/util/LinkedHashMap");
+
+// Java Set<E> Support
+
+/// Set preserving Java iteration order (e.g. `LinkedHashSet`).
+///
+/// Elements are not deduplicated in Rust: a `Set` received from Java is already unique
+/// and duplicates are dropped by the `LinkedHashSet` created on the way out.
+#[derive(Default)]
+pub struct JSet<T>(pub Vec<T>);
+
+impl<T> JSet<T> {
+    pub fn add(&mut self, element: T) {
+        self.0.push(element)
+    }
+}
+
+impl<T: PartialEq> JSet<T> {
+    /// Linear search (use `HashSet` / `BTreeSet` for lookups)
+    pub fn contains(&self, element: &T) -> bool {
+        self.0.contains(element)
+    }
+}
+
+impl<T> FromIterator<T> for JSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for JSet<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+// Iterate Set.iterator()
+fn set_elements_from_j_object<'local, T: 'static>(
+    obj: jni::objects::JObject<'local>,
+    env: &mut jni::JNIEnv<'local>,
+) -> crate::JResult<Vec<T>>
+where
+    jni::objects::JObject<'local>: IntoRustType<'local, T>,
+{
+    let iter = env
+        .call_method(&obj, "iterator", "()Ljava/util/Iterator;", &[])
+        .j_catch_ini(env, "Set.iterator() failed")?
+        .l()?;
+
+    let mut elements = vec![];
+    loop {
+        let has_next = env
+            .call_method(&iter, "hasNext", "()Z", &[])
+            .j_catch_ini(env, "Iterator.hasNext() failed")?
+            .z()?;
+        if !has_next {
+            break;
+        }
+        let element: T = j_in_local_frame(env, |env| {
+            env.call_method(&iter, "next", "()Ljava/lang/Object;", &[])
+                .j_catch_ini(env, "Iterator.next() failed")?
+                .l()?
+                .into_rust(env)
+        })?;
+        elements.push(element);
+    }
+    env.delete_local_ref(iter).j_catch_ini(env, "Set.iterator() failed")?;
+    Ok(elements)
+}
+
+fn set_into_j_object<'local, T>(
+    elements: impl IntoIterator<Item = T>,
+    class_name: &str,
+    env: &mut jni::JNIEnv<'local>,
+) -> crate::JResult<jni::objects::JObject<'local>>
+where
+    T: IntoJavaType<'local, jni::objects::JObject<'local>>,
+{
+    let class = env
+        .find_class(class_name)
+        .j_catch_ini(env, &format!("{class_name} class not found"))?;
+    let set = env
+        .new_object(class, "()V", &[])
+        .j_catch_ini(env, &format!("Failed to create {class_name}"))?;
+
+    for element in elements {
+        j_in_local_frame(env, |env| {
+            let element = element.into_java(env)?;
+            env.call_method(&set, "add", "(Ljava/lang/Object;)Z", &[(&element).into()])
+                .j_catch_ini(env, "Set.add(E) failed")?;
+            Ok(())
+        })?;
+    }
+
+    Ok(set)
+}
+
+// $java_class: Set implementation created on the way out
+macro_rules! j_set_impl {
+    ($set:ident, $java_class:literal) => {
+        impl<'local, T: 'static> IntoRustType<'local, $set<T>> for jni::objects::JObject<'local>
+        where
+            $set<T>: FromIterator<T>,
+            jni::objects::JObject<'local>: IntoRustType<'local, T>,
+        {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<$set<T>> {
+                let elements = set_elements_from_j_object(self, env)?;
+                Ok(elements.into_iter().collect())
+            }
+        }
+
+        impl<'local, T: 'static> IntoRustType<'local, $set<T>>
+            for jni::objects::JValueGen<jni::objects::JObject<'local>>
+        where
+            $set<T>: FromIterator<T>,
+            jni::objects::JObject<'local>: IntoRustType<'local, T>,
+        {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<$set<T>> {
+                let obj = self.l().j_catch_ini(env, "Cast failed [JObject -> Set]")?;
+                let elements = set_elements_from_j_object(obj, env)?;
+                Ok(elements.into_iter().collect())
+            }
+        }
+
+        impl<'local, T> IntoJavaType<'local, jni::objects::JObject<'local>> for $set<T>
+        where
+            T: IntoJavaType<'local, jni::objects::JObject<'local>>,
+        {
+            fn into_java(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::objects::JObject<'local>> {
+                set_into_j_object(self, $java_class, env)
+            }
+        }
+
+        impl<'local, T> JTypeInfo<'local> for $set<T>
+        where
+            T: IntoJavaType<'local, jni::objects::JObject<'local>>,
+        {
+            fn j_return_type() -> jni::signature::ReturnType {
+                jni::signature::ReturnType::Object
+            }
+
+            fn j_type() -> jni::signature::JavaType {
+                jni::signature::JavaType::Object("java/util/Set".to_string())
+            }
+
+            fn into_j_value(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<JValueOwned<'local>> {
+                let obj = set_into_j_object(self, $java_class, env)?;
+                Ok(JValueOwned::Object(obj))
+            }
+        }
+
+        impl<T> JObjectArrayElement for $set<T> {}
+    };
+}
+
+use std::collections::{BTreeSet, HashSet};
+
+// Keeps Java iteration order
+j_set_impl!(JSet, "java/util/LinkedHashSet");
+j_set_impl!(HashSet, "java/util/HashSet");
+// Keeps BTreeSet order
+j_set_impl!(BTreeSet, "java/util/LinkedHashSet");
+
+// Java T[] Support
+
+// Rust types represented by Java objects (element of Java T[])
+pub trait JObjectArrayElement {}
+
+impl JObjectArrayElement for String {}
+impl JObjectArrayElement for JByte {}
+impl JObjectArrayElement for JShort {}
+impl JObjectArrayElement for JInt {}
+impl JObjectArrayElement for JLong {}
+impl JObjectArrayElement for JFloat {}
+impl JObjectArrayElement for JDouble {}
+impl JObjectArrayElement for JBoolean {}
+impl JObjectArrayElement for JChar {}
+impl<T> JObjectArrayElement for JList<T> {}
+impl<T: JObjectArrayElement> JObjectArrayElement for Vec<T> {}
+
+// Class name accepted by FindClass (java/lang/String, [I, ...)
+fn j_class_name(ty: jni::signature::JavaType) -> String {
+    match ty {
+        jni::signature::JavaType::Object(class) => class,
+        other => other.to_string(),
+    }
+}
+
+impl<'local, T> IntoRustType<'local, Vec<T>> for jni::objects::JObjectArray<'local>
+where
+    T: JObjectArrayElement + 'static,
+    jni::objects::JObject<'local>: IntoRustType<'local, T>,
+{
+    fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<T>> {
+        let len = env
+            .get_array_length(&self)
+            .j_catch_ini(env, "Cast failed [T[] -> Vec<T>]")?;
+
+        let mut items = Vec::with_capacity(len as usize);
+        for i in 0..len {
+            let item = j_in_local_frame(env, |env| {
+                env.get_object_array_element(&self, i)
+                    .j_catch_ini(env, "Cast failed [T[] -> Vec<T>]")?
+                    .into_rust(env)
+            })?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+}
+
+impl<'local, T> IntoRustType<'local, Vec<T>> for jni::objects::JObject<'local>
+where
+    T: JObjectArrayElement + 'static,
+    jni::objects::JObject<'local>: IntoRustType<'local, T>,
+{
+    fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<T>> {
+        jni::objects::JObjectArray::from(self).into_rust(env)
+    }
+}
+
+impl<'local, T> IntoRustType<'local, Vec<T>> for jni::objects::JValueGen<jni::objects::JObject<'local>>
+where
+    T: JObjectArrayElement + 'static,
+    jni::objects::JObject<'local>: IntoRustType<'local, T>,
+{
+    fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<T>> {
+        let obj = self.l().j_catch_ini(env, "Cast failed [JObject -> T[]]")?;
+        jni::objects::JObjectArray::from(obj).into_rust(env)
+    }
+}
+
+impl<'local> IntoRustType<'local, jni::objects::JObjectArray<'local>> for jni::objects::JObjectArray<'local> {
+    fn into_rust(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::objects::JObjectArray<'local>> {
+        Ok(self)
+    }
+}
+
+impl<'local, T> IntoJavaType<'local, jni::objects::JObjectArray<'local>> for Vec<T>
+where
+    T: JObjectArrayElement + JTypeInfo<'local> + IntoJavaType<'local, jni::objects::JObject<'local>>,
+{
+    fn into_java(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::objects::JObjectArray<'local>> {
+        let class_name = j_class_name(T::j_type());
+        let class = env
+            .find_class(&class_name)
+            .j_catch_ini(env, &format!("Vec<T> -> T[] ({class_name})"))?;
+        let array = env
+            .new_object_array(self.len() as jni::sys::jsize, class, jni::objects::JObject::null())
+            .j_catch_ini(env, &format!("Vec<T> -> T[] ({class_name})"))?;
+
+        for (i, item) in self.into_iter().enumerate() {
+            j_in_local_frame(env, |env| {
+                let obj = item.into_java(env)?;
+                env.set_object_array_element(&array, i as jni::sys::jsize, obj)
+                    .j_catch_ini(env, &format!("Vec<T> -> T[] ({class_name})"))
+            })?;
+        }
+        Ok(array)
+    }
+}
+
+impl<'local, T> IntoJavaType<'local, jni::objects::JObject<'local>> for Vec<T>
+where
+    T: JObjectArrayElement + JTypeInfo<'local> + IntoJavaType<'local, jni::objects::JObject<'local>>,
+{
+    fn into_java(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::objects::JObject<'local>> {
+        let array: jni::objects::JObjectArray<'local> = self.into_java(env)?;
+        Ok(jni::objects::JObject::from(array))
+    }
+}
+
+impl<'local> IntoJavaType<'local, jni::objects::JObjectArray<'local>> for jni::objects::JObjectArray<'local> {
+    fn into_java(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::objects::JObjectArray<'local>> {
+        Ok(self)
+    }
+}
+
+impl<'local, T> JTypeInfo<'local> for Vec<T>
+where
+    T: JObjectArrayElement + JTypeInfo<'local> + IntoJavaType<'local, jni::objects::JObject<'local>>,
+{
+    fn j_return_type() -> jni::signature::ReturnType {
+        jni::signature::ReturnType::Array
+    }
+
+    fn j_type() -> jni::signature::JavaType {
+        jni::signature::JavaType::Array(Box::new(T::j_type()))
+    }
+
+    fn into_j_value(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<JValueOwned<'local>> {
+        let obj: jni::objects::JObject<'local> = self.into_java(env)?;
+        Ok(JValueOwned::Object(obj))
+    }
+}
+
+#[cfg(test)]
+mod call_tests {
+    use crate as java_bindgen;
+    use crate::prelude::*;
+
+    #[test_jvm]
+    fn should_call_static_and_fields<'a>(
+        test_env: &mut JNIEnv<'a>,
+        _env: JNIEnv<'a>,
+        _class: JClass,
+    ) -> JResult<()> {
+        let max: i32 = call_static("java/lang/Math", "max", (3, 7), test_env)?;
+        assert_eq!(max, 7);
+
+        let value: String = call_static("java/lang/String", "valueOf", (12_i64,), test_env)?;
+        assert_eq!(value, "12");
+
+        let max: i32 = get_static_field("java/lang/Integer", "MAX_VALUE", test_env)?;
+        assert_eq!(max, i32::MAX);
+
+        let text = "java-bindgen".to_string().into_java(test_env)?;
+        let part: String = call_method(&text, "substring", (5, 12), test_env)?;
+        assert_eq!(part, "bindgen");
+        let empty: bool = call_method(&text, "isEmpty", (), test_env)?;
+        assert!(!empty);
+
+        let point = test_env
+            .new_object("java/awt/Point", "()V", &[])
+            .j_catch_ini(test_env, "Failed to create Point")?;
+        set_field(&point, "x", 42, test_env)?;
+        let x: i32 = point.call_getter("getX", test_env).map(|x: f64| x as i32)?;
+        assert_eq!(x, 42);
+        let x: i32 = get_field(&point, "x", test_env)?;
+        assert_eq!(x, 42);
+        Ok(())
+    }
+
+    #[test_jvm]
+    fn should_fail_on_wrong_signature<'a>(
+        test_env: &mut JNIEnv<'a>,
+        _env: JNIEnv<'a>,
+        _class: JClass,
+    ) -> JResult<()> {
+        let result: JResult<String> = call_static("java/lang/Math", "max", (3, 7), test_env);
+        assert!(result.is_err());
+        test_env.exception_clear()?;
+        Ok(())
+    }
+
+    #[test_jvm]
+    fn should_convert_integers_by_policy<'a>(
+        test_env: &mut JNIEnv<'a>,
+        _env: JNIEnv<'a>,
+        _class: JClass,
+    ) -> JResult<()> {
+        assert_eq!("B", u8::j_type().to_string());
+        {
+            let _policy = JIntegerPolicy::Widen.enter();
+            assert_eq!("S", u8::j_type().to_string());
+            let _policy = JIntegerPolicy::BigInteger.enter();
+            assert_eq!("Ljava/math/BigInteger;", u8::j_type().to_string());
+        }
+        assert_eq!("B", u8::j_type().to_string());
+
+        // same width: bits reinterpreted
+        let obj: JObject = u32::MAX.into_java(test_env)?;
+        let value: i64 = call_method(&obj, "longValue", (), test_env)?;
+        assert_eq!(value, -1);
+        let value: u32 = obj.into_rust(test_env)?;
+        assert_eq!(value, u32::MAX);
+
+        // widen: checked
+        let _policy = JIntegerPolicy::Widen.enter();
+        let obj: JObject = u32::MAX.into_java(test_env)?;
+        let value: i64 = call_method(&obj, "longValue", (), test_env)?;
+        assert_eq!(value, u32::MAX as i64);
+        let obj: JObject = (u32::MAX as u64 + 1).into_java(test_env)?;
+        let value: JResult<u32> = obj.into_rust(test_env);
+        assert!(value.is_err());
+        test_env.exception_clear()?;
+        Ok(())
+    }
+
+    #[test_jvm]
+    fn should_round_trip_wrapped_integers_by_policy<'a>(
+        test_env: &mut JNIEnv<'a>,
+        _env: JNIEnv<'a>,
+        _class: JClass,
+    ) -> JResult<()> {
+        for (policy, u32_class) in [
+            (JIntegerPolicy::Widen, "java/lang/Long"),
+            (JIntegerPolicy::BigInteger, "java/math/BigInteger"),
+        ] {
+            let _policy = policy.enter();
+            test_env.with_local_frame(16, |test_env| -> JResult<()> {
+                let obj: JObject = Some(u32::MAX).into_java(test_env)?;
+                assert!(test_env.is_instance_of(&obj, u32_class)?);
+                let value: Option<u32> = jni::objects::JValueGen::Object(obj).into_rust(test_env)?;
+                assert_eq!(value, Some(u32::MAX));
+                let obj: JObject = None::<u32>.into_java(test_env)?;
+                let value: Option<u32> = jni::objects::JValueGen::Object(obj).into_rust(test_env)?;
+                assert_eq!(value, None);
+
+                let list: JObject = JList(vec![0_u64, u64::MAX]).into_java(test_env)?;
+                let last = test_env.call_method(&list, "get", "(I)Ljava/lang/Object;", &[1.into()])?.l()?;
+                assert!(test_env.is_instance_of(&last, "java/math/BigInteger")?);
+                let list: JList<u64> = list.into_rust(test_env)?;
+                assert_eq!(list.0, vec![0, u64::MAX]);
+                Ok(())
+            })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod collection_tests {
+    use crate as java_bindgen;
+    use crate::prelude::*;
+
+    #[test_jvm]
+    fn should_convert_large_set_in_order<'a>(
+        test_env: &mut JNIEnv<'a>,
+        _env: JNIEnv<'a>,
+        _class: JClass,
+    ) -> JResult<()> {
+        // more elements than the default local reference capacity
+        let values: Vec<String> = (0..1000).rev().map(|i| i.to_string()).collect();
+        let set: JObject = JSet(values.clone()).into_java(test_env)?;
+        let set: JSet<String> = set.into_rust(test_env)?;
+        assert_eq!(set.0, values);
+        assert!(set.contains(&"999".to_string()));
+
+        let values: std::collections::BTreeSet<i32> = (0..1000).collect();
+        let set: JObject = values.clone().into_java(test_env)?;
+        let set: std::collections::BTreeSet<i32> = set.into_rust(test_env)?;
+        assert_eq!(set, values);
+        Ok(())
+    }
+
+    #[test_jvm]
+    fn should_convert_large_map<'a>(
+        test_env: &mut JNIEnv<'a>,
+        _env: JNIEnv<'a>,
+        _class: JClass,
+    ) -> JResult<()> {
+        let values: std::collections::BTreeMap<String, i64> = (0..1000).map(|i| (i.to_string(), i)).collect();
+        let map: JObject = values.clone().into_java(test_env)?;
+        let map: std::collections::BTreeMap<String, i64> = map.into_rust(test_env)?;
+        assert_eq!(map, values);
+        Ok(())
+    }
+
+    #[test_jvm]
+    fn should_convert_large_array<'a>(
+        test_env: &mut JNIEnv<'a>,
+        _env: JNIEnv<'a>,
+        _class: JClass,
+    ) -> JResult<()> {
+        let values: Vec<Vec<String>> = (0..1000).map(|i| vec![i.to_string()]).collect();
+        let array: JObject = values.clone().into_java(test_env)?;
+        let array: Vec<Vec<String>> = array.into_rust(test_env)?;
+        assert_eq!(array, values);
+        Ok(())
+    }
+
+    #[test_jvm]
+    fn should_convert_integer_arrays_by_policy<'a>(
+        test_env: &mut JNIEnv<'a>,
+        _env: JNIEnv<'a>,
+        _class: JClass,
+    ) -> JResult<()> {
+        for (policy, u32_array, u64_array) in [
+            (JIntegerPolicy::SameWidth, "[I", "[J"),
+            (JIntegerPolicy::Widen, "[J", "[Ljava/math/BigInteger;"),
+            (JIntegerPolicy::BigInteger, "[Ljava/math/BigInteger;", "[Ljava/math/BigInteger;"),
+        ] {
+            let _policy = policy.enter();
+            assert_eq!(u32_array, Vec::<u32>::j_type().to_string());
+            assert_eq!(u64_array, Vec::<u64>::j_type().to_string());
+
+            test_env.with_local_frame(16, |test_env| -> JResult<()> {
+                let array: JObject = vec![0_u32, u32::MAX].into_java(test_env)?;
+                assert!(test_env.is_instance_of(&array, u32_array)?);
+                let array: Vec<u32> = array.into_rust(test_env)?;
+                assert_eq!(array, vec![0, u32::MAX]);
+
+                let array: JObject = vec![0_u64, u64::MAX].into_java(test_env)?;
+                assert!(test_env.is_instance_of(&array, u64_array)?);
+                let array: Vec<u64> = array.into_rust(test_env)?;
+                assert_eq!(array, vec![0, u64::MAX]);
+                Ok(())
+            })?;
+        }
+
+        // long[] is checked for Vec<u32>
+        let array: JObject = vec![-1_i64].into_java(test_env)?;
+        let result: JResult<Vec<u32>> = array.into_rust(test_env);
+        assert!(result.is_err());
+        test_env.exception_clear()?;
+        Ok(())
+    }
+}
@@ -55,6 +55,48 @@ impl<'local> IntoRustType<'local, u16> for jni::sys::jchar {
     }
 }
 
+// Unsigned integers (same width)
+
+impl<'local> IntoRustType<'local, u32> for jni::sys::jint {
+    fn into_rust(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<u32> {
+        Ok(self as u32)
+    }
+}
+
+impl<'local> IntoRustType<'local, u64> for jni::sys::jlong {
+    fn into_rust(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<u64> {
+        Ok(self as u64)
+    }
+}
+
+impl<'local> IntoRustType<'local, usize> for jni::sys::jlong {
+    fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<usize> {
+        match usize::try_from(self as u64) {
+            Ok(value) => Ok(value),
+            Err(_) => j_out_of_range(self, "usize", env),
+        }
+    }
+}
+
+// Unsigned integers (widened, checked narrowing)
+
+macro_rules! checked_into_rust_impl {
+    ($jni_type:ty, $rust_type:ty) => {
+        impl<'local> IntoRustType<'local, $rust_type> for $jni_type {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<$rust_type> {
+                match <$rust_type>::try_from(self) {
+                    Ok(value) => Ok(value),
+                    Err(_) => j_out_of_range(self, stringify!($rust_type), env),
+                }
+            }
+        }
+    };
+}
+
+checked_into_rust_impl!(jni::sys::jshort, u8);
+checked_into_rust_impl!(jni::sys::jint, u16);
+checked_into_rust_impl!(jni::sys::jlong, u32);
+
 impl<'local> IntoRustType<'local, i16> for jni::sys::jshort {
     fn into_rust(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<i16> {
         Ok(self)
@@ -131,13 +173,6 @@ where
 
 // JValueGen to primitive
 
-impl<'local> IntoRustType<'local, u8> for jni::objects::JValueGen<JObject<'local>> {
-    fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<u8> {
-        let b = self.b().j_catch_ini(env, "Cast failed [JObject -> u8]")?;
-        Ok(b as u8)
-    }
-}
-
 impl<'local> IntoRustType<'local, i8> for jni::objects::JValueGen<JObject<'local>> {
     fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<i8> {
         self.b().j_catch_ini(env, "Cast failed [JObject -> i8]")
@@ -261,17 +296,10 @@ obj_to_class_primitive_impl!(JChar);
 
 // JObject to primitive
 
-impl<'local> IntoRustType<'local, u8> for JObject<'local> {
-    fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<u8> {
-        let value: u8 = self.call_getter("byteValue", env)?;
-        Ok(value)
-    }
-}
-
 impl<'local> IntoRustType<'local, i8> for JObject<'local> {
     fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<i8> {
-        let value: u8 = self.call_getter("byteValue", env)?;
-        Ok(value as i8)
+        let value = self.call_getter("byteValue", env)?;
+        Ok(value)
     }
 }
 
@@ -330,8 +358,108 @@ impl<'local> IntoRustType<'local, Vec<u8>> for JObject<'local> {
     }
 }
 
+// Primitive arrays (bulk copy with Get<Type>ArrayRegion)
+
+macro_rules! primitive_array_into_rust_impl {
+    ($rust_type:ty, $array:ident, $get_region:ident, $name:literal) => {
+        impl<'local> IntoRustType<'local, Vec<$rust_type>> for jni::objects::$array<'local> {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<$rust_type>> {
+                let msg = concat!("Cast failed [", $name, " -> Vec<", stringify!($rust_type), ">]");
+                let len = env.get_array_length(&self).j_catch_ini(env, msg)?;
+                let mut buf = vec![<$rust_type>::default(); len as usize];
+                env.$get_region(&self, 0, &mut buf).j_catch_ini(env, msg)?;
+                Ok(buf)
+            }
+        }
+
+        impl<'local> IntoRustType<'local, Vec<$rust_type>> for JObject<'local> {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<$rust_type>> {
+                jni::objects::$array::from(self).into_rust(env)
+            }
+        }
+
+        impl<'local> IntoRustType<'local, Vec<$rust_type>> for jni::objects::JValueGen<JObject<'local>> {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<$rust_type>> {
+                let msg = concat!("Cast failed [JObject -> ", $name, "]");
+                let obj = self.l().j_catch_ini(env, msg)?;
+                obj.into_rust(env)
+            }
+        }
+
+        impl<'local> IntoRustType<'local, jni::objects::$array<'local>> for jni::objects::$array<'local> {
+            fn into_rust(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::objects::$array<'local>> {
+                Ok(self)
+            }
+        }
+    };
+}
+
+primitive_array_into_rust_impl!(i16, JShortArray, get_short_array_region, "short[]");
+primitive_array_into_rust_impl!(u16, JCharArray, get_char_array_region, "char[]");
+primitive_array_into_rust_impl!(i32, JIntArray, get_int_array_region, "int[]");
+primitive_array_into_rust_impl!(i64, JLongArray, get_long_array_region, "long[]");
+primitive_array_into_rust_impl!(f32, JFloatArray, get_float_array_region, "float[]");
+primitive_array_into_rust_impl!(f64, JDoubleArray, get_double_array_region, "double[]");
+
+impl<'local> IntoRustType<'local, Vec<char>> for jni::objects::JCharArray<'local> {
+    fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<char>> {
+        let chars: Vec<u16> = self.into_rust(env)?;
+        let chars: Option<Vec<char>> = chars.into_iter().map(|c| char::from_u32(c as u32)).collect();
+        match chars {
+            Some(chars) => Ok(chars),
+            None => JException::from_class_and_msg(
+                JExceptionClass::ClassCastException,
+                "Cast failed [char[] -> Vec<char>] (surrogate char)",
+            )
+            .throw(env),
+        }
+    }
+}
+
+impl<'local> IntoRustType<'local, Vec<char>> for JObject<'local> {
+    fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<char>> {
+        jni::objects::JCharArray::from(self).into_rust(env)
+    }
+}
+
+impl<'local> IntoRustType<'local, Vec<char>> for jni::objects::JValueGen<JObject<'local>> {
+    fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<char>> {
+        let obj = self.l().j_catch_ini(env, "Cast failed [JObject -> char[]]")?;
+        obj.into_rust(env)
+    }
+}
+
+impl<'local> IntoRustType<'local, Vec<bool>> for jni::objects::JBooleanArray<'local> {
+    fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<bool>> {
+        let msg = "Cast failed [boolean[] -> Vec<bool>]";
+        let len = env.get_array_length(&self).j_catch_ini(env, msg)?;
+        let mut buf = vec![jni::sys::JNI_FALSE; len as usize];
+        env.get_boolean_array_region(&self, 0, &mut buf).j_catch_ini(env, msg)?;
+        Ok(buf.into_iter().map(|v| v == jni::sys::JNI_TRUE).collect())
+    }
+}
+
+impl<'local> IntoRustType<'local, Vec<bool>> for JObject<'local> {
+    fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<bool>> {
+        jni::objects::JBooleanArray::from(self).into_rust(env)
+    }
+}
+
+impl<'local> IntoRustType<'local, Vec<bool>> for jni::objects::JValueGen<JObject<'local>> {
+    fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<Vec<bool>> {
+        let obj = self.l().j_catch_ini(env, "Cast failed [JObject -> boolean[]]")?;
+        obj.into_rust(env)
+    }
+}
+
 // Raw JNI types (Allows pass raw type as fn argument)
 
+impl<'local> IntoRustType<'local, jni::objects::JBooleanArray<'local>> for jni::objects::JBooleanArray<'local> {
+    fn into_rust(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::objects::JBooleanArray<'local>> {
+        Ok(self)
+    }
+}
+
 impl<'local> IntoRustType<'local, JString<'local>> for JString<'local> {
     fn into_rust(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<JString<'local>> {
         Ok(self)
@@ -348,4 +476,18 @@ impl<'local> IntoRustType<'local, JObject<'local>> for JObject<'local> {
     fn into_rust(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<JObject<'local>> {
         Ok(self)
     }
-}
\ No newline at end of file
+}
+
+// Java Void
+
+impl<'local> IntoRustType<'local, ()> for JObject<'local> {
+    fn into_rust(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<()> {
+        Ok(())
+    }
+}
+
+impl<'local> IntoRustType<'local, ()> for jni::objects::JValueGen<JObject<'local>> {
+    fn into_rust(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,293 @@
+// java.time conversions
+//
+// `std::time::Duration` is always available.
+// `chrono` and `time` types are enabled with the cargo features of the same name.
+#![cfg_attr(not(any(feature = "chrono", feature = "time")), allow(dead_code))]
+
+use jni::objects::{JObject, JValue};
+
+use crate::interop::j_out_of_range;
+use crate::java_types::{call_int, call_long, call_static_object, java_object_impl};
+use crate::prelude::*;
+
+// java.time.Instant (epoch seconds, nanos)
+
+fn new_instant<'local>(seconds: i64, nanos: i64, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+    let args = [JValue::Long(seconds), JValue::Long(nanos)];
+    call_static_object("java/time/Instant", "ofEpochSecond", "(JJ)Ljava/time/Instant;", &args, env)
+}
+
+#[cfg_attr(not(feature = "chrono"), allow(dead_code))]
+fn read_instant(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<(i64, i32)> {
+    let seconds = call_long(obj, "getEpochSecond", env)?;
+    let nanos = call_int(obj, "getNano", env)?;
+    Ok((seconds, nanos))
+}
+
+// java.time.OffsetDateTime (epoch seconds, nanos, offset seconds)
+
+fn new_offset_date_time<'local>(
+    seconds: i64,
+    nanos: i64,
+    offset: i32,
+    env: &mut JNIEnv<'local>,
+) -> JResult<JObject<'local>> {
+    let instant = new_instant(seconds, nanos, env)?;
+    let zone = call_static_object(
+        "java/time/ZoneOffset",
+        "ofTotalSeconds",
+        "(I)Ljava/time/ZoneOffset;",
+        &[JValue::Int(offset)],
+        env,
+    )?;
+    call_static_object(
+        "java/time/OffsetDateTime",
+        "ofInstant",
+        "(Ljava/time/Instant;Ljava/time/ZoneId;)Ljava/time/OffsetDateTime;",
+        &[(&instant).into(), (&zone).into()],
+        env,
+    )
+}
+
+fn read_offset_date_time(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<(i64, i32, i32)> {
+    let seconds = call_long(obj, "toEpochSecond", env)?;
+    let nanos = call_int(obj, "getNano", env)?;
+    let zone = env
+        .call_method(obj, "getOffset", "()Ljava/time/ZoneOffset;", &[])
+        .j_catch_ini(env, "Call Java getter: getOffset()")?
+        .l()?;
+    let offset = call_int(&zone, "getTotalSeconds", env)?;
+    Ok((seconds, nanos, offset))
+}
+
+// java.time.LocalDate (year, month, day)
+
+fn new_local_date<'local>(year: i32, month: u32, day: u32, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+    let args = [JValue::Int(year), JValue::Int(month as i32), JValue::Int(day as i32)];
+    call_static_object("java/time/LocalDate", "of", "(III)Ljava/time/LocalDate;", &args, env)
+}
+
+fn read_local_date(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<(i32, i32, i32)> {
+    let year = call_int(obj, "getYear", env)?;
+    let month = call_int(obj, "getMonthValue", env)?;
+    let day = call_int(obj, "getDayOfMonth", env)?;
+    Ok((year, month, day))
+}
+
+// java.time.LocalDateTime ((year, month, day), (hour, minute, second, nanos))
+
+fn new_local_date_time<'local>(
+    (year, month, day): (i32, u32, u32),
+    (hour, minute, second, nanos): (u32, u32, u32, u32),
+    env: &mut JNIEnv<'local>,
+) -> JResult<JObject<'local>> {
+    let args = [year, month as i32, day as i32, hour as i32, minute as i32, second as i32, nanos as i32]
+        .map(JValue::Int);
+    call_static_object("java/time/LocalDateTime", "of", "(IIIIIII)Ljava/time/LocalDateTime;", &args, env)
+}
+
+#[allow(clippy::type_complexity)]
+fn read_local_date_time(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<((i32, i32, i32), (i32, i32, i32, i32))> {
+    let date = read_local_date(obj, env)?;
+    let hour = call_int(obj, "getHour", env)?;
+    let minute = call_int(obj, "getMinute", env)?;
+    let second = call_int(obj, "getSecond", env)?;
+    let nanos = call_int(obj, "getNano", env)?;
+    Ok((date, (hour, minute, second, nanos)))
+}
+
+// java.time.Duration (seconds, nanos)
+
+fn new_duration<'local>(seconds: i64, nanos: i64, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+    let args = [JValue::Long(seconds), JValue::Long(nanos)];
+    call_static_object("java/time/Duration", "ofSeconds", "(JJ)Ljava/time/Duration;", &args, env)
+}
+
+fn read_duration(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<(i64, i32)> {
+    let seconds = call_long(obj, "getSeconds", env)?;
+    let nanos = call_int(obj, "getNano", env)?;
+    Ok((seconds, nanos))
+}
+
+// std::time::Duration <-> java.time.Duration
+
+fn std_duration_from_java(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<std::time::Duration> {
+    let (seconds, nanos) = read_duration(obj, env)?;
+    match u64::try_from(seconds) {
+        Ok(seconds) => Ok(std::time::Duration::new(seconds, nanos as u32)),
+        Err(_) => j_out_of_range(format!("{seconds}s"), "std::time::Duration", env),
+    }
+}
+
+fn std_duration_into_java<'local>(value: std::time::Duration, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+    match i64::try_from(value.as_secs()) {
+        Ok(seconds) => new_duration(seconds, value.subsec_nanos() as i64, env),
+        Err(_) => j_out_of_range(format!("{value:?}"), "java.time.Duration", env),
+    }
+}
+
+java_object_impl!(std::time::Duration, "java/time/Duration", std_duration_from_java, std_duration_into_java);
+
+#[cfg(feature = "chrono")]
+mod chrono_impl {
+    use super::*;
+    use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+
+    // DateTime<Utc> <-> java.time.Instant
+
+    fn utc_from_java(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<DateTime<Utc>> {
+        let (seconds, nanos) = read_instant(obj, env)?;
+        match DateTime::from_timestamp(seconds, nanos as u32) {
+            Some(value) => Ok(value),
+            None => j_out_of_range(format!("{seconds}s"), "DateTime<Utc>", env),
+        }
+    }
+
+    fn utc_into_java<'local>(value: DateTime<Utc>, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+        new_instant(value.timestamp(), value.timestamp_subsec_nanos() as i64, env)
+    }
+
+    java_object_impl!(DateTime<Utc>, "java/time/Instant", utc_from_java, utc_into_java);
+
+    // DateTime<FixedOffset> <-> java.time.OffsetDateTime
+
+    fn fixed_from_java(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<DateTime<FixedOffset>> {
+        let (seconds, nanos, offset) = read_offset_date_time(obj, env)?;
+        let value = DateTime::from_timestamp(seconds, nanos as u32)
+            .zip(FixedOffset::east_opt(offset))
+            .map(|(value, offset)| value.with_timezone(&offset));
+        match value {
+            Some(value) => Ok(value),
+            None => j_out_of_range(format!("{seconds}s"), "DateTime<FixedOffset>", env),
+        }
+    }
+
+    fn fixed_into_java<'local>(value: DateTime<FixedOffset>, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+        let offset = value.offset().local_minus_utc();
+        new_offset_date_time(value.timestamp(), value.timestamp_subsec_nanos() as i64, offset, env)
+    }
+
+    java_object_impl!(DateTime<FixedOffset>, "java/time/OffsetDateTime", fixed_from_java, fixed_into_java);
+
+    // NaiveDate <-> java.time.LocalDate
+
+    fn date_from_java(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<NaiveDate> {
+        let (year, month, day) = read_local_date(obj, env)?;
+        match NaiveDate::from_ymd_opt(year, month as u32, day as u32) {
+            Some(value) => Ok(value),
+            None => j_out_of_range(format!("{year}-{month}-{day}"), "NaiveDate", env),
+        }
+    }
+
+    fn date_into_java<'local>(value: NaiveDate, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+        new_local_date(value.year(), value.month(), value.day(), env)
+    }
+
+    java_object_impl!(NaiveDate, "java/time/LocalDate", date_from_java, date_into_java);
+
+    // NaiveDateTime <-> java.time.LocalDateTime
+
+    fn date_time_from_java(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<NaiveDateTime> {
+        let ((year, month, day), (hour, minute, second, nanos)) = read_local_date_time(obj, env)?;
+        let date = NaiveDate::from_ymd_opt(year, month as u32, day as u32);
+        let time = NaiveTime::from_hms_nano_opt(hour as u32, minute as u32, second as u32, nanos as u32);
+        match date.zip(time) {
+            Some((date, time)) => Ok(date.and_time(time)),
+            None => j_out_of_range(format!("{year}-{month}-{day}"), "NaiveDateTime", env),
+        }
+    }
+
+    fn date_time_into_java<'local>(value: NaiveDateTime, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+        let date = (value.year(), value.month(), value.day());
+        let time = (value.hour(), value.minute(), value.second(), value.nanosecond());
+        new_local_date_time(date, time, env)
+    }
+
+    java_object_impl!(NaiveDateTime, "java/time/LocalDateTime", date_time_from_java, date_time_into_java);
+}
+
+#[cfg(feature = "time")]
+mod time_impl {
+    use super::*;
+    use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+    // time::OffsetDateTime <-> java.time.OffsetDateTime
+
+    fn offset_from_java(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<OffsetDateTime> {
+        let (seconds, nanos, offset) = read_offset_date_time(obj, env)?;
+        let timestamp = seconds as i128 * 1_000_000_000 + nanos as i128;
+        let value = OffsetDateTime::from_unix_timestamp_nanos(timestamp)
+            .ok()
+            .zip(UtcOffset::from_whole_seconds(offset).ok())
+            .and_then(|(value, offset)| value.checked_to_offset(offset));
+        match value {
+            Some(value) => Ok(value),
+            None => j_out_of_range(format!("{seconds}s"), "time::OffsetDateTime", env),
+        }
+    }
+
+    fn offset_into_java<'local>(value: OffsetDateTime, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+        let offset = value.offset().whole_seconds();
+        new_offset_date_time(value.unix_timestamp(), value.nanosecond() as i64, offset, env)
+    }
+
+    java_object_impl!(OffsetDateTime, "java/time/OffsetDateTime", offset_from_java, offset_into_java);
+
+    // time::Date <-> java.time.LocalDate
+
+    fn to_date(year: i32, month: i32, day: i32) -> Option<Date> {
+        let month = Month::try_from(u8::try_from(month).ok()?).ok()?;
+        Date::from_calendar_date(year, month, u8::try_from(day).ok()?).ok()
+    }
+
+    fn date_from_java(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<Date> {
+        let (year, month, day) = read_local_date(obj, env)?;
+        match to_date(year, month, day) {
+            Some(value) => Ok(value),
+            None => j_out_of_range(format!("{year}-{month}-{day}"), "time::Date", env),
+        }
+    }
+
+    fn date_into_java<'local>(value: Date, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+        new_local_date(value.year(), value.month() as u32, value.day() as u32, env)
+    }
+
+    java_object_impl!(Date, "java/time/LocalDate", date_from_java, date_into_java);
+
+    // time::PrimitiveDateTime <-> java.time.LocalDateTime
+
+    fn date_time_from_java(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<PrimitiveDateTime> {
+        let ((year, month, day), (hour, minute, second, nanos)) = read_local_date_time(obj, env)?;
+        let time = Time::from_hms_nano(hour as u8, minute as u8, second as u8, nanos as u32).ok();
+        match to_date(year, month, day).zip(time) {
+            Some((date, time)) => Ok(PrimitiveDateTime::new(date, time)),
+            None => j_out_of_range(format!("{year}-{month}-{day}"), "time::PrimitiveDateTime", env),
+        }
+    }
+
+    fn date_time_into_java<'local>(value: PrimitiveDateTime, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+        let date = (value.year(), value.month() as u32, value.day() as u32);
+        let time = (
+            value.hour() as u32,
+            value.minute() as u32,
+            value.second() as u32,
+            value.nanosecond(),
+        );
+        new_local_date_time(date, time, env)
+    }
+
+    java_object_impl!(PrimitiveDateTime, "java/time/LocalDateTime", date_time_from_java, date_time_into_java);
+
+    // time::Duration <-> java.time.Duration
+
+    fn duration_from_java(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<time::Duration> {
+        let (seconds, nanos) = read_duration(obj, env)?;
+        Ok(time::Duration::new(seconds, nanos))
+    }
+
+    fn duration_into_java<'local>(value: time::Duration, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+        new_duration(value.whole_seconds(), value.subsec_nanoseconds() as i64, env)
+    }
+
+    java_object_impl!(time::Duration, "java/time/Duration", duration_from_java, duration_into_java);
+}
@@ -0,0 +1,209 @@
+// Java library types (java.nio.file.Path, java.util.UUID, java.math.BigDecimal)
+//
+// `PathBuf` is always available.
+// `uuid` and `rust_decimal` types are enabled with the cargo features of the same name.
+
+use jni::objects::{JObject, JValue};
+
+use crate::prelude::*;
+
+pub(crate) fn j_check_null(obj: &JObject, rust_type: &str, env: &mut JNIEnv<'_>) -> JResult<()> {
+    if obj.is_null() {
+        return JException::from_class_and_msg(
+            JExceptionClass::NullPointerException,
+            &format!("Cast failed [null -> {rust_type}]"),
+        )
+        .throw(env);
+    }
+    Ok(())
+}
+
+pub(crate) fn call_int(obj: &JObject, name: &str, env: &mut JNIEnv<'_>) -> JResult<i32> {
+    env.call_method(obj, name, "()I", &[])
+        .j_catch_ini(env, &format!("Call Java getter: {name}()"))?
+        .i()
+        .j_catch_ini(env, &format!("Call Java getter: {name}()"))
+}
+
+pub(crate) fn call_long(obj: &JObject, name: &str, env: &mut JNIEnv<'_>) -> JResult<i64> {
+    env.call_method(obj, name, "()J", &[])
+        .j_catch_ini(env, &format!("Call Java getter: {name}()"))?
+        .j()
+        .j_catch_ini(env, &format!("Call Java getter: {name}()"))
+}
+
+pub(crate) fn call_string(obj: &JObject, name: &str, env: &mut JNIEnv<'_>) -> JResult<String> {
+    let value = env
+        .call_method(obj, name, "()Ljava/lang/String;", &[])
+        .j_catch_ini(env, &format!("Call Java getter: {name}()"))?
+        .l()
+        .j_catch_ini(env, &format!("Call Java getter: {name}()"))?;
+    value.into_rust(env)
+}
+
+pub(crate) fn call_static_object<'local>(
+    class: &str,
+    name: &str,
+    sig: &str,
+    args: &[JValue],
+    env: &mut JNIEnv<'local>,
+) -> JResult<JObject<'local>> {
+    env.call_static_method(class, name, sig, args)
+        .j_catch_ini(env, &format!("Call Java method: {class}.{name}()"))?
+        .l()
+        .j_catch_ini(env, &format!("Call Java method: {class}.{name}()"))
+}
+
+// $from_java: fn(&JObject, &mut JNIEnv) -> JResult<$rust_type>
+// $into_java: fn($rust_type, &mut JNIEnv) -> JResult<JObject>
+macro_rules! java_object_impl {
+    ($rust_type:ty, $java_class:literal, $from_java:ident, $into_java:ident) => {
+        impl<'local> $crate::j2r::IntoRustType<'local, $rust_type> for jni::objects::JObject<'local> {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> $crate::JResult<$rust_type> {
+                $crate::java_types::j_check_null(&self, stringify!($rust_type), env)?;
+                $from_java(&self, env)
+            }
+        }
+
+        impl<'local> $crate::j2r::IntoRustType<'local, $rust_type>
+            for jni::objects::JValueGen<jni::objects::JObject<'local>>
+        {
+            fn into_rust(self, env: &mut jni::JNIEnv<'local>) -> $crate::JResult<$rust_type> {
+                let obj = self
+                    .l()
+                    .j_catch_ini(env, concat!("Cast failed [JObject -> ", stringify!($rust_type), "]"))?;
+                obj.into_rust(env)
+            }
+        }
+
+        impl<'local> $crate::r2j::IntoJavaType<'local, jni::objects::JObject<'local>> for $rust_type {
+            fn into_java(self, env: &mut jni::JNIEnv<'local>) -> $crate::JResult<jni::objects::JObject<'local>> {
+                $into_java(self, env)
+            }
+        }
+
+        impl<'local> $crate::interop::JTypeInfo<'local> for $rust_type {
+            fn j_return_type() -> jni::signature::ReturnType {
+                jni::signature::ReturnType::Object
+            }
+
+            fn j_type() -> jni::signature::JavaType {
+                jni::signature::JavaType::Object($java_class.to_string())
+            }
+
+            fn into_j_value(
+                self,
+                env: &mut jni::JNIEnv<'local>,
+            ) -> $crate::JResult<jni::objects::JValueOwned<'local>> {
+                Ok(jni::objects::JValueOwned::Object($into_java(self, env)?))
+            }
+        }
+
+        impl $crate::interop::JObjectArrayElement for $rust_type {}
+    };
+}
+
+pub(crate) use java_object_impl;
+
+// std::path::PathBuf <-> java.nio.file.Path
+
+fn path_from_java(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<std::path::PathBuf> {
+    let value = call_string(obj, "toString", env)?;
+    Ok(std::path::PathBuf::from(value))
+}
+
+fn path_into_java<'local>(value: std::path::PathBuf, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+    let Some(value) = value.to_str() else {
+        return JException::from_class_and_msg(
+            JExceptionClass::IllegalArgumentException,
+            &format!("Path is not valid UTF-8: {}", value.display()),
+        )
+        .throw(env);
+    };
+    let value = env.new_string(value).j_catch_ini(env, "Failed to create String")?;
+    let more = env
+        .new_object_array(0, "java/lang/String", JObject::null())
+        .j_catch_ini(env, "Failed to create String[]")?;
+    call_static_object(
+        "java/nio/file/Paths",
+        "get",
+        "(Ljava/lang/String;[Ljava/lang/String;)Ljava/nio/file/Path;",
+        &[(&value).into(), (&more).into()],
+        env,
+    )
+}
+
+java_object_impl!(std::path::PathBuf, "java/nio/file/Path", path_from_java, path_into_java);
+
+#[cfg(feature = "uuid")]
+mod uuid_impl {
+    use super::*;
+    use uuid::Uuid;
+
+    // Uuid <-> java.util.UUID (most / least significant bits)
+
+    fn uuid_from_java(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<Uuid> {
+        let msb = call_long(obj, "getMostSignificantBits", env)? as u64;
+        let lsb = call_long(obj, "getLeastSignificantBits", env)? as u64;
+        Ok(Uuid::from_u128(((msb as u128) << 64) | lsb as u128))
+    }
+
+    fn uuid_into_java<'local>(value: Uuid, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+        let value = value.as_u128();
+        let msb = (value >> 64) as u64 as i64;
+        let lsb = value as u64 as i64;
+        env.new_object("java/util/UUID", "(JJ)V", &[JValue::Long(msb), JValue::Long(lsb)])
+            .j_catch_ini(env, "Failed to create UUID")
+    }
+
+    java_object_impl!(Uuid, "java/util/UUID", uuid_from_java, uuid_into_java);
+}
+
+#[cfg(feature = "rust_decimal")]
+mod decimal_impl {
+    use super::*;
+    use crate::interop::j_out_of_range;
+    use rust_decimal::Decimal;
+
+    // Decimal <-> java.math.BigDecimal (unscaled value, scale)
+
+    fn decimal_from_java(obj: &JObject, env: &mut JNIEnv<'_>) -> JResult<Decimal> {
+        let unscaled = env
+            .call_method(obj, "unscaledValue", "()Ljava/math/BigInteger;", &[])
+            .j_catch_ini(env, "Call Java getter: unscaledValue()")?
+            .l()
+            .j_catch_ini(env, "Call Java getter: unscaledValue()")?;
+        let unscaled = call_string(&unscaled, "toString", env)?;
+        let scale = call_int(obj, "scale", env)?;
+
+        // Negative scale: unscaled * 10^-scale
+        let value = unscaled.parse::<i128>().ok().and_then(|mantissa| match u32::try_from(scale) {
+            Ok(scale) => Decimal::try_from_i128_with_scale(mantissa, scale).ok(),
+            Err(_) => {
+                let factor = 10i128.checked_pow(scale.unsigned_abs())?;
+                Decimal::try_from_i128_with_scale(mantissa.checked_mul(factor)?, 0).ok()
+            }
+        });
+        match value {
+            Some(value) => Ok(value),
+            None => {
+                let value = call_string(obj, "toString", env)?;
+                j_out_of_range(value, "Decimal", env)
+            }
+        }
+    }
+
+    fn decimal_into_java<'local>(value: Decimal, env: &mut JNIEnv<'local>) -> JResult<JObject<'local>> {
+        let unscaled = env
+            .new_string(value.mantissa().to_string())
+            .j_catch_ini(env, "Failed to create String")?;
+        let unscaled = env
+            .new_object("java/math/BigInteger", "(Ljava/lang/String;)V", &[(&unscaled).into()])
+            .j_catch_ini(env, "Failed to create BigInteger")?;
+        let scale = JValue::Int(value.scale() as i32);
+        env.new_object("java/math/BigDecimal", "(Ljava/math/BigInteger;I)V", &[(&unscaled).into(), scale])
+            .j_catch_ini(env, "Failed to create BigDecimal")
+    }
+
+    java_object_impl!(Decimal, "java/math/BigDecimal", decimal_from_java, decimal_into_java);
+}
@@ -1,12 +1,20 @@
 #![doc = include_str!("../README.md")]
-#![forbid(unsafe_code, clippy::unwrap_used)]
+#![deny(unsafe_code)]
+#![forbid(clippy::unwrap_used)]
 
 pub use jni;
+pub mod array;
+pub mod buffer;
+pub mod callback;
 pub mod exception;
+pub mod handle;
 pub mod interop;
+mod java_time;
+mod java_types;
 pub mod j2r;
 pub mod r2j;
 pub mod logger;
+pub mod proxy;
 pub use exception::JResult;
 pub mod test_utils;
 
@@ -14,7 +22,9 @@ pub mod test_utils;
 pub extern crate java_bindgen_macro as derive;
 
 pub mod prelude {
-    pub use crate::derive::{java_bindgen, test_jvm, JavaClass, IntoJava, IntoRust, JavaType, JLogger};
+    pub use crate::derive::{java_bindgen, java_interface, test_jvm, JavaClass, JavaException, IntoJava, IntoRust, JavaType, JLogger};
+    pub use crate::buffer::{JDirectBuffer, JOwnedBuffer};
+    pub use crate::callback::JCallback;
     pub use crate::interop::*;
     pub use crate::j2r::*;
     pub use crate::r2j::*;
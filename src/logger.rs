@@ -1,4 +1,9 @@
-use crate::{interop::*, prelude::JavaCatch, JResult};
+use crate::{
+    interop::*,
+    j2r::IntoRustType,
+    prelude::{JavaCatch, JavaCatchINI},
+    JResult,
+};
 
 #[derive(Default)]
 pub struct JLoggerCore<'a> {
@@ -7,12 +12,7 @@ pub struct JLoggerCore<'a> {
 
 impl<'a> JLoggerCore<'a> {
     pub fn new(env: &mut jni::JNIEnv<'a>, lib_class_path: &str) -> JResult<Self> {
-        let class = env.find_class(lib_class_path).j_catch(env)?;
-        let logger = env
-            .get_static_field(&class, "logger", "Lorg/slf4j/Logger;")
-            .j_catch(env)?;
-        let logger_obj = logger.l().j_catch(env)?;
-
+        let Slf4jLogger(logger_obj) = get_static_field(lib_class_path, "logger", env)?;
         Ok(JLoggerCore { logger_obj })
     }
 
@@ -55,3 +55,27 @@ impl<'a> JLoggerCore<'a> {
         self._log(msg, "trace", env);
     }
 }
+
+// org.slf4j.Logger (Lib.logger field)
+struct Slf4jLogger<'a>(jni::objects::JObject<'a>);
+
+impl<'a> JTypeInfo<'a> for Slf4jLogger<'a> {
+    fn j_return_type() -> jni::signature::ReturnType {
+        jni::signature::ReturnType::Object
+    }
+
+    fn j_type() -> jni::signature::JavaType {
+        jni::signature::JavaType::Object("org/slf4j/Logger".to_string())
+    }
+
+    fn into_j_value(self, _: &mut jni::JNIEnv<'a>) -> JResult<jni::objects::JValueOwned<'a>> {
+        Ok(jni::objects::JValueOwned::Object(self.0))
+    }
+}
+
+impl<'a> IntoRustType<'a, Slf4jLogger<'a>> for jni::objects::JValueGen<jni::objects::JObject<'a>> {
+    fn into_rust(self, env: &mut jni::JNIEnv<'a>) -> JResult<Slf4jLogger<'a>> {
+        let logger = self.l().j_catch_ini(env, "Cast failed [JObject -> Logger]")?;
+        Ok(Slf4jLogger(logger))
+    }
+}
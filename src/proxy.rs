@@ -0,0 +1,63 @@
+// Java interface proxies (#[java_interface(class = "com.acme.Repo")] trait Repo {})
+//
+// The macro generates `JRepo` wrapping the Java object and implements the trait with
+// `j_proxy_call`. Arguments are converted with `JTypeInfo::into_j_value`,
+// the result with `IntoRustType`. A Java exception is cleared and returned as `JException`.
+
+use jni::objects::{GlobalRef, JObject, JValue, JValueGen, JValueOwned};
+use jni::signature::TypeSignature;
+
+use crate::exception::j_take_exception;
+use crate::prelude::*;
+
+/// Global reference to Java object implementing `class_path` (com/acme/Repo)
+pub fn j_proxy_object(
+    obj: JObject<'_>,
+    class_path: &str,
+    env: &mut JNIEnv<'_>,
+) -> JResult<GlobalRef> {
+    let class_name = class_path.replace('/', ".");
+    if obj.is_null() {
+        return JException::from_class_and_msg(
+            JExceptionClass::NullPointerException,
+            &format!("Cast failed [null -> {class_name}]"),
+        )
+        .throw(env);
+    }
+
+    let instance = env
+        .is_instance_of(&obj, class_path)
+        .j_catch_ini(env, &format!("Class not found: {class_name}"))?;
+    if !instance {
+        return JException::from_class_and_msg(
+            JExceptionClass::ClassCastException,
+            &format!("Cast failed [Object -> {class_name}]"),
+        )
+        .throw(env);
+    }
+    env.new_global_ref(obj).j_catch_ini(env, "Failed to create GlobalRef")
+}
+
+/// Call Java instance method
+pub fn j_proxy_call<'local, R>(
+    obj: &JObject<'_>,
+    name: &str,
+    sig: TypeSignature,
+    args: Vec<JValueOwned<'local>>,
+    env: &mut JNIEnv<'local>,
+) -> JResult<R>
+where
+    R: JTypeInfo<'local>,
+    JValueGen<JObject<'local>>: IntoRustType<'local, R>,
+{
+    // ReturnType::Object has no class name (same as call_getter)
+    let args_sig: String = sig.args.iter().map(|arg| arg.to_string()).collect();
+    let sig = format!("({args_sig}){}", R::j_type());
+
+    let values: Vec<JValue> = args.iter().map(|arg| arg.borrow()).collect();
+    match env.call_method(obj, name, &sig, &values) {
+        Ok(result) => result.into_rust(env),
+        Err(jni::errors::Error::JavaException) => Err(j_take_exception(env)),
+        Err(err) => Err(err).j_catch_ini(env, &format!("Call Java method: {name}{sig}")),
+    }
+}
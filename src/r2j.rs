@@ -58,12 +58,6 @@ for jni::objects::JByteArray<'local>
 
 // Class primitives (Auto cast from primitives )
 
-impl<'local> IntoJavaType<'local, JObject<'local>> for u8 {
-    fn into_java(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<JObject<'local>> {
-        JByte(self as i8).into_java(env)
-    }
-}
-
 impl<'local> IntoJavaType<'local, JObject<'local>> for i8 {
     fn into_java(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<JObject<'local>> {
         let class = env
@@ -226,6 +220,46 @@ impl<'local> IntoJavaType<'local, jni::sys::jchar> for u16 {
     }
 }
 
+// Unsigned integers (same width)
+
+impl<'local> IntoJavaType<'local, jni::sys::jint> for u32 {
+    fn into_java(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::sys::jint> {
+        Ok(self as i32)
+    }
+}
+
+impl<'local> IntoJavaType<'local, jni::sys::jlong> for u64 {
+    fn into_java(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::sys::jlong> {
+        Ok(self as i64)
+    }
+}
+
+impl<'local> IntoJavaType<'local, jni::sys::jlong> for usize {
+    fn into_java(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::sys::jlong> {
+        Ok(self as i64)
+    }
+}
+
+// Unsigned integers (widened)
+
+impl<'local> IntoJavaType<'local, jni::sys::jshort> for u8 {
+    fn into_java(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::sys::jshort> {
+        Ok(self as i16)
+    }
+}
+
+impl<'local> IntoJavaType<'local, jni::sys::jint> for u16 {
+    fn into_java(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::sys::jint> {
+        Ok(self as i32)
+    }
+}
+
+impl<'local> IntoJavaType<'local, jni::sys::jlong> for u32 {
+    fn into_java(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::sys::jlong> {
+        Ok(self as i64)
+    }
+}
+
 impl<'local> IntoJavaType<'local, jni::sys::jfloat> for f32 {
     fn into_java(self, _: &mut jni::JNIEnv<'local>) -> crate::JResult<jni::sys::jfloat> {
         Ok(self)
@@ -358,6 +392,115 @@ impl<'local> IntoJavaType<'local, jni::objects::JObject<'local>> for &[u8] {
     }
 }
 
+// Primitive arrays (bulk copy with Set<Type>ArrayRegion)
+
+macro_rules! primitive_array_into_java_impl {
+    ($rust_type:ty, $array:ident, $new_array:ident, $set_region:ident, $name:literal) => {
+        impl<'local> IntoJavaType<'local, jni::objects::$array<'local>> for &[$rust_type] {
+            fn into_java(
+                self,
+                env: &mut jni::JNIEnv<'local>,
+            ) -> crate::JResult<jni::objects::$array<'local>> {
+                let msg = concat!("&[", stringify!($rust_type), "] -> ", $name);
+                let array = env.$new_array(self.len() as jni::sys::jsize).j_catch_ini(env, msg)?;
+                env.$set_region(&array, 0, self).j_catch_ini(env, msg)?;
+                Ok(array)
+            }
+        }
+
+        impl<'local> IntoJavaType<'local, jni::objects::$array<'local>> for Vec<$rust_type> {
+            fn into_java(
+                self,
+                env: &mut jni::JNIEnv<'local>,
+            ) -> crate::JResult<jni::objects::$array<'local>> {
+                self.as_slice().into_java(env)
+            }
+        }
+
+        impl<'local> IntoJavaType<'local, JObject<'local>> for Vec<$rust_type> {
+            fn into_java(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<JObject<'local>> {
+                let array: jni::objects::$array<'local> = self.into_java(env)?;
+                Ok(JObject::from(array))
+            }
+        }
+
+        impl<'local> IntoJavaType<'local, jni::objects::$array<'local>> for jni::objects::$array<'local> {
+            fn into_java(
+                self,
+                _: &mut jni::JNIEnv<'local>,
+            ) -> crate::JResult<jni::objects::$array<'local>> {
+                Ok(self)
+            }
+        }
+    };
+}
+
+primitive_array_into_java_impl!(i16, JShortArray, new_short_array, set_short_array_region, "short[]");
+primitive_array_into_java_impl!(u16, JCharArray, new_char_array, set_char_array_region, "char[]");
+primitive_array_into_java_impl!(i32, JIntArray, new_int_array, set_int_array_region, "int[]");
+primitive_array_into_java_impl!(i64, JLongArray, new_long_array, set_long_array_region, "long[]");
+primitive_array_into_java_impl!(f32, JFloatArray, new_float_array, set_float_array_region, "float[]");
+primitive_array_into_java_impl!(f64, JDoubleArray, new_double_array, set_double_array_region, "double[]");
+
+impl<'local> IntoJavaType<'local, jni::objects::JCharArray<'local>> for Vec<char> {
+    fn into_java(
+        self,
+        env: &mut jni::JNIEnv<'local>,
+    ) -> crate::JResult<jni::objects::JCharArray<'local>> {
+        let mut chars = Vec::with_capacity(self.len());
+        for c in self {
+            // Java char is a UTF-16 code unit (chars outside the BMP need a surrogate pair)
+            match u16::try_from(c as u32) {
+                Ok(c) => chars.push(c),
+                Err(_) => {
+                    return JException::from_class_and_msg(
+                        JExceptionClass::IllegalArgumentException,
+                        &format!("Cast failed [Vec<char> -> char[]] ({c:?} is not a UTF-16 code unit)"),
+                    )
+                    .throw(env)
+                }
+            }
+        }
+        chars.into_java(env)
+    }
+}
+
+impl<'local> IntoJavaType<'local, JObject<'local>> for Vec<char> {
+    fn into_java(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<JObject<'local>> {
+        let array: jni::objects::JCharArray<'local> = self.into_java(env)?;
+        Ok(JObject::from(array))
+    }
+}
+
+impl<'local> IntoJavaType<'local, jni::objects::JBooleanArray<'local>> for Vec<bool> {
+    fn into_java(
+        self,
+        env: &mut jni::JNIEnv<'local>,
+    ) -> crate::JResult<jni::objects::JBooleanArray<'local>> {
+        let msg = "Vec<bool> -> boolean[]";
+        let values: Vec<jni::sys::jboolean> = self.into_iter().map(jni::sys::jboolean::from).collect();
+        let array = env.new_boolean_array(values.len() as jni::sys::jsize).j_catch_ini(env, msg)?;
+        env.set_boolean_array_region(&array, 0, &values).j_catch_ini(env, msg)?;
+        Ok(array)
+    }
+}
+
+impl<'local> IntoJavaType<'local, JObject<'local>> for Vec<bool> {
+    fn into_java(self, env: &mut jni::JNIEnv<'local>) -> crate::JResult<JObject<'local>> {
+        let array: jni::objects::JBooleanArray<'local> = self.into_java(env)?;
+        Ok(JObject::from(array))
+    }
+}
+
+impl<'local> IntoJavaType<'local, jni::objects::JBooleanArray<'local>> for jni::objects::JBooleanArray<'local> {
+    fn into_java(
+        self,
+        _: &mut jni::JNIEnv<'local>,
+    ) -> crate::JResult<jni::objects::JBooleanArray<'local>> {
+        Ok(self)
+    }
+}
+
 // String (JObject)
 
 impl<'local> IntoJavaType<'local, jni::objects::JObject<'local>> for String {
@@ -379,3 +522,28 @@ impl<'local> IntoJavaType<'local, jni::objects::JObject<'local>> for &str {
         Ok(JObject::from(string))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate as java_bindgen;
+    use crate::prelude::*;
+
+    #[test_jvm]
+    fn should_reject_chars_outside_bmp<'a>(
+        test_env: &mut JNIEnv<'a>,
+        _env: JNIEnv<'a>,
+        _class: JClass,
+    ) -> JResult<()> {
+        let array: jni::objects::JCharArray = vec!['a', 'é'].into_java(test_env)?;
+        let chars: Vec<char> = array.into_rust(test_env)?;
+        assert_eq!(chars, vec!['a', 'é']);
+
+        let result: JResult<jni::objects::JCharArray> = vec!['a', '🦀'].into_java(test_env);
+        assert!(result.is_err());
+        assert!(test_env.exception_check()?);
+        let exception = test_env.exception_occurred()?;
+        test_env.exception_clear()?;
+        assert!(test_env.is_instance_of(&exception, "java/lang/IllegalArgumentException")?);
+        Ok(())
+    }
+}